@@ -3,8 +3,8 @@
 /*
  * In this example, we short-circuit RX & TX, and plug this to the DATA half-duplex port.
  * A no-op zero-cost Dummy direction pin is provided to the controller.
- * We'll therefore receive all the packets we send, before actually receiving the real response.
- * and the controller is configured to expect 2 Response after set commands
+ * We'll therefore receive all the packets we send, before actually receiving the real response,
+ * so the controller is configured to check and discard this echo.
  *
  * the AX12A has the default configuration: Protocol 1, Baudrate: 115_200, device ID: 1
  */
@@ -17,8 +17,8 @@ use heapless::Vec;
 
 use dmx::{
     ax12a::AX12A,
-    protocol::{Controller, Instruction, Protocol},
-    serialport_embedded_hal::Serial,
+    protocol::{Controller, Echo, Instruction, Protocol, Timing},
+    serialport_embedded_hal::{Delay, Serial},
 };
 
 fn main() {
@@ -26,10 +26,16 @@ fn main() {
     let baudrate = 115_200;
 
     let port = serialport::new("/dev/ttyACM0", baudrate).timeout(time::Duration::from_millis(100));
-    let serial = Serial::new(port);
+    let serial = Serial::new(port).unwrap();
 
     let dummy_pin = DummyPin::new_low();
-    let mut dmx = Controller::new_1(serial, dummy_pin, 0);
+    let mut dmx = Controller::new_1(serial, dummy_pin, Echo::Discard).with_timing(
+        Delay,
+        Timing {
+            latency: 20_000, // USB adapters can be slow
+            ..Timing::new(baudrate)
+        },
+    );
 
     println!("auto ping: {:?}", dmx.ping(id));
 
@@ -37,7 +43,6 @@ fn main() {
     dmx.send(id, Instruction::Ping, Vec::<u8, 0>::new())
         .unwrap();
     println!("recv: {:?}", dmx.recv::<0>());
-    println!("recv: {:?}", dmx.recv::<0>());
 
     println!("manual read:");
     dmx.send(
@@ -47,7 +52,6 @@ fn main() {
     )
     .unwrap();
     println!("recv: {:?}", dmx.recv::<2>());
-    println!("recv: {:?}", dmx.recv::<2>());
 
     println!("get pose: {:?}", dmx.get_ax12a_present_position(id));
 
@@ -0,0 +1,54 @@
+#![deny(unsafe_code)]
+
+/*
+ * Same setup as linux-xl320, but on a tokio runtime:
+ * we short-circuit RX & TX, and plug this to the DATA half-duplex port,
+ * so the controller is configured to check and discard the echo of our packets.
+ *
+ * the XL320 is set to 115200 baud (1M by default), with the default device ID: 1
+ */
+
+use std::time;
+
+use dummy_pin::DummyPin;
+
+use dmx::{
+    protocol::{Controller, Echo, Timing},
+    protocol_async::AsyncProtocol,
+    tokio_serial_embedded_io::{Delay, Serial},
+    xl320::XL320Async,
+};
+
+fn main() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    runtime.block_on(run());
+}
+
+async fn run() {
+    let id = 1;
+    let baudrate = 115_200;
+
+    let port = tokio_serial::new("/dev/ttyACM0", baudrate);
+    let serial = Serial::new(port).unwrap();
+
+    let dummy_pin = DummyPin::new_low();
+    let mut dmx = Controller::new_2(serial, dummy_pin, Echo::Discard).with_timing(
+        Delay,
+        Timing {
+            latency: 20_000, // USB adapters can be slow
+            ..Timing::new(baudrate)
+        },
+    );
+
+    println!("ping: {:?}", dmx.ping(id).await);
+
+    loop {
+        for led in 0..8 {
+            println!("set led {}: {:?}", led, dmx.set_xl320_led(id, led).await);
+            tokio::time::sleep(time::Duration::from_secs(1)).await;
+        }
+    }
+}
@@ -3,7 +3,8 @@
 
 /*
  * In this example, we short-circuit RX & TX, and plug this to the DATA half-duplex port.
- * We'll therefore receive all the packets we send, before actually receiving the real response.
+ * We'll therefore receive all the packets we send, before actually receiving the real response,
+ * so the controller is configured to check and discard this echo.
  * A no-op zero-cost Dummy direction pin is provided to the controller.
  *
  * the XL320 has the default configuration: Baudrate: 1M, device ID: 1
@@ -16,8 +17,8 @@ use dummy_pin::DummyPin;
 use heapless::Vec;
 
 use dmx::{
-    protocol::{Controller, Instruction, Protocol},
-    serialport_embedded_hal::Serial,
+    protocol::{Controller, Echo, Instruction, Protocol, Timing},
+    serialport_embedded_hal::{Delay, Serial},
     xl320::XL320,
 };
 
@@ -26,14 +27,19 @@ fn main() {
     let baudrate = 115_200;
 
     let port = serialport::new("/dev/ttyACM0", baudrate).timeout(time::Duration::from_millis(100));
-    let serial = Serial::new(port);
+    let serial = Serial::new(port).unwrap();
 
     let dummy_pin = DummyPin::new_low();
-    let mut dmx = Controller::new_2(serial, dummy_pin, 0);
+    let mut dmx = Controller::new_2(serial, dummy_pin, Echo::Discard).with_timing(
+        Delay,
+        Timing {
+            latency: 20_000, // USB adapters can be slow
+            ..Timing::new(baudrate)
+        },
+    );
 
     dmx.send(id, Instruction::Ping, Vec::<u8, 0>::new())
         .unwrap();
-    println!("recv: {:?}", dmx.recv::<4>()); // Err Instruction Received
     println!("recv: {:?}", dmx.recv::<4>()); // Ok received response {…}
 
     loop {
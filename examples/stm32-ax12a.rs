@@ -4,14 +4,18 @@
 
 /*
  * In this example, we plug only TX to the DATA half-duplex port, so this is write-only,
- * and the controller is configured to expect 0 Response after set commands
+ * and the controller is configured to expect no Status Packet after set commands
  * A no-op zero-cost Dummy direction pin is provided to the controller.
  *
  * the AX12A has the following configuration: Protocol 1, Baudrate: 115_200, device ID: 1
  */
 
 use cortex_m_rt::entry;
-use dmx::{ax12a::AX12A, protocol::Controller};
+use dmx::{
+    ax12a::AX12A,
+    embedded_hal_02,
+    protocol::{Controller, Echo, StatusReturnLevel},
+};
 use dummy_pin::DummyPin;
 use nb::block;
 use panic_rtt_target as _;
@@ -69,7 +73,9 @@ fn main() -> ! {
         clocks,
         &mut rcc.apb2,
     );
-    let mut dmx = Controller::new_1(serial, dummy_pin, 0);
+    let serial = embedded_hal_02::Serial::new(serial);
+    let mut dmx = Controller::new_1(serial, dummy_pin, Echo::None);
+    dmx.status_return_level = StatusReturnLevel::PingOnly;
     sleep_ms(&mut timer, 500);
 
     for led in 0..6 {
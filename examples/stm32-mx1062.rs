@@ -4,13 +4,14 @@
 
 use cortex_m_rt::entry;
 use dmx::{
+    embedded_hal_02,
     mx1062::MX1062,
-    protocol::{Controller, Protocol},
+    protocol::{Controller, Echo, Protocol, Release, Timing},
 };
 use nb::block;
 use panic_rtt_target as _;
 use rtt_target::{rprintln, rtt_init_print};
-use stm32f1xx_hal::{pac, prelude::*, serial, timer::Timer};
+use stm32f1xx_hal::{delay::Delay, pac, prelude::*, serial, timer::Timer};
 
 #[entry]
 fn main() -> ! {
@@ -25,7 +26,8 @@ fn main() -> ! {
 
     let mut afio = dp.AFIO.constrain(&mut rcc.apb2);
     let clocks = rcc.cfgr.freeze(&mut flash.acr);
-    let mut timer = Timer::syst(cp.SYST, &clocks).start_count_down(1.khz());
+    let mut timer = Timer::tim2(dp.TIM2, &clocks, &mut rcc.apb1).start_count_down(1.khz());
+    let delay = embedded_hal_02::Delay(Delay::new(cp.SYST, clocks));
 
     let mut gpioa = dp.GPIOA.split(&mut rcc.apb2);
 
@@ -47,7 +49,15 @@ fn main() -> ! {
         clocks,
         &mut rcc.apb1,
     );
-    let mut dmx = Controller::new_2(serial, dir, 1);
+    let serial = embedded_hal_02::Serial::new(serial);
+    let dir = embedded_hal_02::Pin(dir);
+    let mut dmx = Controller::new_2(serial, dir, Echo::None).with_timing(
+        delay,
+        Timing {
+            release: Release::LastByte, // let the last byte leave the shift register
+            ..Timing::new(baudrate)
+        },
+    );
 
     let mut i = false;
 
@@ -4,14 +4,18 @@
 
 /*
  * In this example, we plug only TX to the DATA half-duplex port, so this is write-only,
- * and the controller is configured to expect 0 Response after set commands
+ * and the controller is configured to expect no Status Packet after set commands
  * A no-op zero-cost Dummy direction pin is provided to the controller.
  *
  * the XL320 has the following configuration: Baudrate: 115_200, device ID: 1
  */
 
 use cortex_m_rt::entry;
-use dmx::{protocol::Controller, xl320::XL320};
+use dmx::{
+    embedded_hal_02,
+    protocol::{Controller, Echo, StatusReturnLevel},
+    xl320::XL320,
+};
 use dummy_pin::DummyPin;
 use nb::block;
 use panic_rtt_target as _;
@@ -69,7 +73,9 @@ fn main() -> ! {
         clocks,
         &mut rcc.apb2,
     );
-    let mut dmx = Controller::new_2(serial, dummy_pin, 0);
+    let serial = embedded_hal_02::Serial::new(serial);
+    let mut dmx = Controller::new_2(serial, dummy_pin, Echo::None);
+    dmx.status_return_level = StatusReturnLevel::PingOnly;
 
     rprintln!(
         "set torque enable 1: {:?}",
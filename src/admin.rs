@@ -0,0 +1,238 @@
+//! Changes of ID and baud rate, and control table backups, checked so that devices don't get lost
+
+use crate::control_table::{self, Model, Register};
+use crate::protocol::{
+    foreign, write_params, Backup, Controller, Error, Instruction, Protocol, MAX_ID,
+};
+use embedded_hal::delay::DelayNs;
+use embedded_io::{ErrorType, Read, ReadReady, Write};
+
+/// Serial ports whose baud rate can be changed, to follow a device to its new baud rate
+pub trait SetBaudRate: ErrorType {
+    fn set_baud_rate(&mut self, baud_rate: u32) -> Result<(), Self::Error>;
+}
+
+/// Pings to a device which applies its new settings
+const ATTEMPTS: u8 = 10;
+
+/// Wait between these pings, in µs
+const WAIT: u32 = 10_000;
+
+/// Write a new ID at `address`, whose status may come from the old or the new ID
+///
+/// Stale status packets from other devices are dropped.
+pub(crate) fn write_id<P, Serial, const PROTOCOL_VERSION: u8>(
+    dmx: &mut P,
+    id: u8,
+    address: u16,
+    new_id: u8,
+) -> Result<(), Error<Serial>>
+where
+    P: Protocol<Serial, PROTOCOL_VERSION>,
+    Serial: Read + ReadReady + Write,
+{
+    let content = write_params::<_, PROTOCOL_VERSION, 3>(address, &[new_id])?;
+    dmx.send(id, Instruction::Write, content)?;
+    if dmx.returns_status(id, Instruction::Write) {
+        let status = loop {
+            match dmx.recv::<0>() {
+                Ok(status) if status.packet_id == id || status.packet_id == new_id => break status,
+                Ok(_) => {}
+                Err(e) if foreign(id, &e) && foreign(new_id, &e) => {}
+                Err(e) => return Err(e),
+            }
+        };
+        if let Some(error) = status.error_code() {
+            return Err(Error::Status {
+                id: status.packet_id,
+                error,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Check that `id` is a valid ID, which no device answers to
+pub(crate) fn check_free<P, Serial, const PROTOCOL_VERSION: u8>(
+    dmx: &mut P,
+    id: u8,
+) -> Result<(), Error<Serial>>
+where
+    P: Protocol<Serial, PROTOCOL_VERSION>,
+    Serial: Read + ReadReady + Write,
+{
+    if id > MAX_ID {
+        return Err(Error::InvalidId { id });
+    }
+    if dmx.ping(id)? {
+        return Err(Error::IdInUse { id });
+    }
+    Ok(())
+}
+
+/// Write a new baud rate `value` in `register`, whose status may be lost as the device
+/// switches before it is out
+pub(crate) fn write_baud_rate<P, Serial, const PROTOCOL_VERSION: u8>(
+    dmx: &mut P,
+    id: u8,
+    register: &Register,
+    value: u32,
+) -> Result<(), Error<Serial>>
+where
+    P: Protocol<Serial, PROTOCOL_VERSION>,
+    Serial: Read + ReadReady + Write,
+{
+    let data = value.to_le_bytes();
+    match dmx.write::<6, 0>(id, register.address, &data[..usize::from(register.size)]) {
+        Ok(_) | Err(Error::Timeout) | Err(Error::CrcError { .. }) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+impl<Serial, Direction, const PROTOCOL_VERSION: u8, Delay>
+    Controller<Serial, Direction, PROTOCOL_VERSION, Delay>
+where
+    Self: Protocol<Serial, PROTOCOL_VERSION>,
+    Serial: Read + ReadReady + Write,
+    Delay: DelayNs,
+{
+    /// Model of device `id`, from its model number
+    pub fn model_of(&mut self, id: u8) -> Result<&'static Model, Error<Serial>> {
+        let model_number = u16::from_le_bytes(self.read(id, 0)?);
+        control_table::model(model_number).ok_or(Error::UnknownModel { id, model_number })
+    }
+
+    /// Change the ID of device `old` to `new`, and check that it answers there
+    ///
+    /// `new` must be free. Torque is disabled to write the EEPROM, and stays disabled.
+    /// This needs timeouts, to find that `new` is free: it fails with `Untimed` otherwise.
+    pub fn change_id(&mut self, old: u8, new: u8) -> Result<(), Error<Serial>> {
+        self.timed()?;
+        if old > MAX_ID {
+            return Err(Error::InvalidId { id: old });
+        }
+        if !self.ping(old)? {
+            return Err(Error::NoDevice { id: old });
+        }
+        if old == new {
+            return Ok(());
+        }
+        check_free(self, new)?;
+        let model = self.model_of(old)?;
+        let register = model.register("id").ok_or(Error::UnknownModel {
+            id: old,
+            model_number: model.model_number,
+        })?;
+        self.disable_torque(old, model)?;
+        write_id(self, old, register.address, new)?;
+        self.come_back(new)?;
+
+        // the device keeps its status return level
+        self.status_return_levels[usize::from(new)] = self.status_return_levels[usize::from(old)];
+        self.status_return_levels[usize::from(old)] = None;
+        Ok(())
+    }
+
+    /// Change the baud rate of device `id`, follow it with the serial port, and check that it
+    /// answers at its new baud rate
+    ///
+    /// Other devices on the bus stay at the previous baud rate.
+    /// Torque is disabled to write the EEPROM, and stays disabled.
+    /// This needs timeouts, to wait for the device: it fails with `Untimed` otherwise.
+    pub fn change_baud(&mut self, id: u8, baud_rate: u32) -> Result<(), Error<Serial>>
+    where
+        Serial: SetBaudRate,
+    {
+        self.timed()?;
+        if !self.ping(id)? {
+            return Err(Error::NoDevice { id });
+        }
+        let model = self.model_of(id)?;
+        let value = model
+            .baud_rate_value(baud_rate)
+            .ok_or(Error::UnsupportedBaudRate { baud_rate })?;
+        let register = model
+            .register("baud_rate")
+            .ok_or(Error::UnsupportedBaudRate { baud_rate })?;
+        self.disable_torque(id, model)?;
+        write_baud_rate(self, id, register, value)?;
+        // timing.baud_rate may not be the speed of the port, e.g. with Timing::UNTIMED
+        self.serial
+            .set_baud_rate(baud_rate)
+            .map_err(Error::Communication)?;
+        self.timing.baud_rate = baud_rate;
+        self.come_back(id)
+    }
+
+    /// Store the control table of device `id` to its backup area, and wait until it is ready
+    ///
+    /// Torque is disabled for the backup, and stays disabled.
+    /// When there was no backup yet, `backup_ready` tells when the new one is done. Otherwise
+    /// it already reads 1: the wait is then best-effort, until the device answers again.
+    /// This needs timeouts: it fails with `Untimed` otherwise.
+    pub fn backup_control_table(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        self.timed()?;
+        let model = self.model_of(id)?;
+        let backup_ready = model.register("backup_ready").ok_or(Error::Unsupported)?;
+        let previous = self.read::<1>(id, backup_ready.address)? == [1];
+        self.disable_torque(id, model)?;
+        self.control_table_backup(id, Backup::Store)?;
+        if previous {
+            return self.come_back(id);
+        }
+        for _ in 0..ATTEMPTS {
+            self.delay.delay_us(WAIT);
+            if self.read::<1>(id, backup_ready.address)? == [1] {
+                return Ok(());
+            }
+        }
+        Err(Error::NoBackup { id })
+    }
+
+    /// Restore the control table of device `id` from its backup area, and wait until it answers
+    ///
+    /// Torque is disabled for the restore, and stays disabled.
+    /// This needs timeouts: it fails with `Untimed` otherwise.
+    pub fn restore_control_table(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        self.timed()?;
+        let model = self.model_of(id)?;
+        let backup_ready = model.register("backup_ready").ok_or(Error::Unsupported)?;
+        if self.read::<1>(id, backup_ready.address)? != [1] {
+            return Err(Error::NoBackup { id });
+        }
+        self.disable_torque(id, model)?;
+        self.control_table_backup(id, Backup::Restore)?;
+        self.come_back(id)
+    }
+
+    /// Check that waits for devices time out
+    fn timed(&self) -> Result<(), Error<Serial>> {
+        match self.timing.timeout_bytes {
+            Some(_) => Ok(()),
+            None => Err(Error::Untimed),
+        }
+    }
+
+    fn disable_torque(&mut self, id: u8, model: &Model) -> Result<(), Error<Serial>> {
+        match model.register("torque_enable") {
+            Some(torque_enable) => self
+                .write::<3, 0>(id, torque_enable.address, &[0])
+                .map(drop),
+            None => Ok(()),
+        }
+    }
+
+    /// Wait until device `id` answers
+    fn come_back(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        for _ in 0..ATTEMPTS {
+            self.delay.delay_us(WAIT);
+            // answers may be garbled while the device restarts
+            match self.ping(id) {
+                Ok(true) => return Ok(()),
+                Ok(false) | Err(Error::CrcError { .. }) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Err(Error::NoDevice { id })
+    }
+}
@@ -0,0 +1,243 @@
+//! Interactive terminal to send Dynamixel packets and see the bytes on the bus
+//!
+//! Each line is a packet, starting with the protocol version, then either:
+//! - its content in hex, without header nor checksum, sent as typed even if malformed:
+//!   `2 1 6 0 3 19 0 1` (XL320 ID 1, length 6, Write, address 0x19, LED 1)
+//!   or `1 1 4 3 19 1` (AX12A ID 1, length 4, Write, address 0x19, LED on)
+//! - or an instruction by name, the ID and params in hex: `2 write 1 19 0 1`
+//!
+//! Each Status Packet received is decoded, `q` quits.
+//!
+//! usage: dmx-term [-p PORT] [-b BAUDRATE] [PACKET...]
+//! With a packet on the command line, it is sent, and we quit 2 seconds later.
+
+use std::io::{self, BufRead};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+use std::{env, process, thread, time::Duration};
+
+use dmx::protocol::{Decode, Instruction, StatusPacket};
+use dmx::{protocol_1, protocol_2, serialport_embedded_hal::Serial};
+use serialport::{ClearBuffer, SerialPort};
+
+type Error = dmx::protocol::Error<Serial>;
+
+const MAX_PARAMS_SIZE: usize = 256;
+
+enum Decoder {
+    Protocol1(protocol_1::Decoder<MAX_PARAMS_SIZE>),
+    Protocol2(protocol_2::Decoder<MAX_PARAMS_SIZE>),
+}
+
+impl Decoder {
+    fn new(protocol: u8) -> Self {
+        match protocol {
+            1 => Self::Protocol1(protocol_1::Decoder::new()),
+            _ => Self::Protocol2(protocol_2::Decoder::new()),
+        }
+    }
+
+    fn push(&mut self, byte: u8) -> Option<Result<StatusPacket<MAX_PARAMS_SIZE>, Error>> {
+        match self {
+            Self::Protocol1(decoder) => decoder.push(byte),
+            Self::Protocol2(decoder) => decoder.push(byte),
+        }
+    }
+}
+
+/// Show a byte in decimal, hexadecimal and ASCII,
+/// in the middle of the line if we sent it, or at its end if we received it
+fn show(byte: u8, received: bool) {
+    let c = if byte.is_ascii_graphic() {
+        char::from(byte)
+    } else {
+        ' '
+    };
+    let column = format!("{byte:3} 0x{byte:02x} {c}");
+    let width = env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse::<usize>().ok())
+        .unwrap_or(80)
+        .saturating_sub(1);
+    if received {
+        println!("←{column:>width$}");
+    } else {
+        println!("→{column:^width$}");
+    }
+}
+
+fn hex(word: &str) -> Result<u8, String> {
+    u8::from_str_radix(word.trim_start_matches("0x"), 16)
+        .map_err(|e| format!("{word} is not a byte in hex: {e}"))
+}
+
+/// Build the Instruction Packet described by a line
+fn packet(line: &str) -> Result<(u8, Vec<u8>), String> {
+    let mut words = line.split_whitespace();
+    let protocol = words.next().ok_or("no protocol version")?;
+    let protocol = match protocol {
+        "1" | "2" => hex(protocol)?,
+        _ => return Err(format!("protocol {protocol} is not 1 or 2")),
+    };
+    let words: Vec<&str> = words.collect();
+    if let Some(Ok(instruction)) = words.first().map(|word| word.parse::<Instruction>()) {
+        // instruction by name, then ID and params
+        let id = hex(words.get(1).ok_or("no ID")?)?;
+        let params: Vec<u8> = words[2..]
+            .iter()
+            .map(|w| hex(w))
+            .collect::<Result<_, _>>()?;
+        let bytes = match protocol {
+            1 => protocol_1::frame::<Serial>(id, instruction, &params)
+                .map(|frame| frame.iter().copied().collect()),
+            _ => protocol_2::frame::<Serial>(id, instruction, &params)
+                .map(|frame| frame.iter().copied().collect()),
+        };
+        return bytes
+            .map(|bytes| (protocol, bytes))
+            .map_err(|e: Error| e.to_string());
+    }
+    // raw content: ID, length, instruction, params; only the header and checksum are added
+    let content = words
+        .iter()
+        .map(|w| hex(w))
+        .collect::<Result<Vec<u8>, _>>()?;
+    let length_size = usize::from(protocol);
+    if content.len() < 2 + length_size {
+        return Err("too short: an ID, a length and an instruction are needed".into());
+    }
+    let length = content[1..=length_size]
+        .iter()
+        .rev()
+        .fold(0, |length, &b| length * 256 + usize::from(b));
+    // from the instruction to the checksum or CRC
+    let expected = content.len() - 1;
+    if length != expected {
+        println!("length should be {expected}, not {length}: sent anyway");
+    }
+    let mut bytes = match protocol {
+        1 => vec![0xFF, 0xFF],
+        _ => vec![0xFF, 0xFF, 0xFD, 0x00],
+    };
+    bytes.extend_from_slice(&content);
+    if protocol == 1 {
+        let sum = content.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        bytes.push(!sum);
+    } else {
+        let mut crc = crc16::State::<crc16::BUYPASS>::new();
+        crc.update(&bytes);
+        bytes.extend_from_slice(&crc.get().to_le_bytes());
+    }
+    Ok((protocol, bytes))
+}
+
+fn send(port: &mut dyn SerialPort, protocol: &AtomicU8, line: &str) {
+    match packet(line) {
+        Ok((version, bytes)) => {
+            protocol.store(version, Ordering::Relaxed);
+            if let Err(e) = port.write_all(&bytes) {
+                println!("write error: {e}");
+            }
+            for byte in bytes {
+                show(byte, false);
+            }
+        }
+        Err(e) => println!("{e}"),
+    }
+}
+
+/// Show and decode what comes from the bus
+fn receive(mut port: Box<dyn SerialPort>, protocol: Arc<AtomicU8>) {
+    let mut version = protocol.load(Ordering::Relaxed);
+    let mut decoder = Decoder::new(version);
+    let mut buffer = [0; 256];
+    loop {
+        let n = match port.read(&mut buffer) {
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => continue,
+            Err(e) => {
+                println!("read error: {e}");
+                return;
+            }
+        };
+        if protocol.load(Ordering::Relaxed) != version {
+            version = protocol.load(Ordering::Relaxed);
+            decoder = Decoder::new(version);
+        }
+        for &byte in &buffer[..n] {
+            show(byte, true);
+            if let Some(status) = decoder.push(byte) {
+                match status {
+                    Ok(packet) => println!("checksum ok: {packet:?}"),
+                    Err(e) => println!("{e}"),
+                }
+                decoder = Decoder::new(version);
+            }
+        }
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: dmx-term [-p PORT] [-b BAUDRATE] [PACKET...]");
+    process::exit(2);
+}
+
+fn main() {
+    let mut path = String::from("/dev/ttyACM0");
+    let mut baudrate = 115_200;
+    let mut args = env::args().skip(1);
+    let mut packet = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--port" => path = args.next().unwrap_or_else(|| usage()),
+            "-b" | "--baudrate" => {
+                baudrate = args
+                    .next()
+                    .and_then(|b| b.parse().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            "-h" | "--help" => usage(),
+            _ => packet.push(arg),
+        }
+    }
+
+    let mut port = match serialport::new(&path, baudrate)
+        .timeout(Duration::from_millis(100))
+        .open()
+    {
+        Ok(port) => port,
+        Err(e) => {
+            eprintln!("can't open {path}: {e}");
+            process::exit(1);
+        }
+    };
+    port.clear(ClearBuffer::Input).ok();
+    let reader = port.try_clone().expect("can't clone the serial port");
+    let protocol = Arc::new(AtomicU8::new(2));
+    {
+        let protocol = protocol.clone();
+        thread::spawn(move || receive(reader, protocol));
+    }
+
+    if !packet.is_empty() {
+        send(port.as_mut(), &protocol, &packet.join(" "));
+        thread::sleep(Duration::from_secs(2));
+        return;
+    }
+
+    println!("xl320 led 1: 2 1 6 0 3 19 0 1");
+    println!("xl320 led 2: 2 1 6 0 3 19 0 2");
+    println!("xl320 led 3: 2 write 1 19 0 3");
+    println!("ax12a led 0: 1 1 4 3 19 0");
+    println!("ax12a led 1: 1 write 1 19 1");
+    for line in io::stdin().lock().lines() {
+        let Ok(line) = line else { break };
+        if line.trim().to_lowercase().starts_with('q') {
+            break;
+        }
+        if !line.trim().is_empty() {
+            send(port.as_mut(), &protocol, &line);
+        }
+    }
+    println!("exiting...");
+}
@@ -0,0 +1,387 @@
+//! Administration of the devices on a bus
+//!
+//! usage: dmx [-p PORT] [-b BAUDRATE] [-P PROTOCOL] [-e] [-m MODEL] COMMAND
+//!
+//! Registers are named as in the generated getters, e.g. `goal_position` or `goal-position`,
+//! in the control table of the model reported by the device, unless `-m` names another one.
+//! Values are in decimal, or in hex with `0x`.
+
+use std::convert::TryFrom;
+use std::{env, fs, process, time::Duration};
+
+use dmx::config::{Config, DeviceConfig};
+use dmx::control_table::{self, Model, Register};
+use dmx::protocol::{
+    Controller, Echo, FactoryReset, Protocol, StatusPacket, Timing, BROADCAST_ID, MAX_ID,
+};
+use dmx::serialport_embedded_hal::{Delay, Serial};
+use dmx::snapshot::Snapshot;
+use dummy_pin::DummyPin;
+
+const USAGE: &str = "usage: dmx [-p PORT] [-b BAUDRATE] [-P PROTOCOL] [-e] [-m MODEL] COMMAND
+
+options:
+    -p, --port PORT          serial port, /dev/ttyACM0 by default
+    -b, --baudrate BAUDRATE  115200 by default
+    -P, --protocol 1|2       protocol version, 2 by default
+    -e, --echo               RX and TX are short-circuited: discard our own packets
+    -m, --model MODEL        control table to use, e.g. xl320, instead of the reported model
+
+commands:
+    scan                          ping every ID, and show the model of those which answer
+    ping ID
+    read ID REGISTER
+    write ID REGISTER VALUE
+    stage ID REGISTER VALUE       register a write, done on the next action
+    action [ID]                   do the registered writes, of every device by default
+    dump ID [json]                read every register, as a table or as JSON
+    reboot ID
+    factory-reset ID [all|except-id|except-id-baud]
+    clear-multi-turn ID           bring the position back within a turn
+    backup ID                     store the control table to the backup area of the device
+    restore ID                    restore the control table from the backup area of the device
+    set-id ID NEW_ID              NEW_ID must be free
+    set-baud ID BAUDRATE          the port follows the device to its new baud rate
+    capture FILE ID...            save the EEPROM of these devices, as TOML if FILE ends in .toml
+    apply FILE                    write the values of FILE which differ on its devices
+    check FILE                    show the values which differ from FILE, and fail if any";
+
+struct Options {
+    path: String,
+    baudrate: u32,
+    protocol: u8,
+    echo: Echo,
+    model: Option<&'static Model>,
+    command: Vec<String>,
+}
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    process::exit(2);
+}
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+fn number(word: &str) -> i64 {
+    let (digits, negative) = match word.strip_prefix('-') {
+        Some(digits) => (digits, true),
+        None => (word, false),
+    };
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => i64::from_str_radix(hex, 16),
+        None => digits.parse(),
+    }
+    .unwrap_or_else(|_| fail(format!("{word} is not a number")));
+    if negative {
+        -value
+    } else {
+        value
+    }
+}
+
+fn id(word: &str) -> u8 {
+    match u8::try_from(number(word)) {
+        Ok(id) if id <= MAX_ID || id == BROADCAST_ID => id,
+        _ => fail(format!("{word} is not an ID")),
+    }
+}
+
+fn options() -> Options {
+    let mut options = Options {
+        path: String::from("/dev/ttyACM0"),
+        baudrate: 115_200,
+        protocol: 2,
+        echo: Echo::None,
+        model: None,
+        command: Vec::new(),
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--port" => options.path = args.next().unwrap_or_else(|| usage()),
+            "-b" | "--baudrate" => {
+                options.baudrate = args
+                    .next()
+                    .and_then(|b| b.parse().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            "-P" | "--protocol" => {
+                options.protocol = match args.next().as_deref() {
+                    Some("1") => 1,
+                    Some("2") => 2,
+                    _ => usage(),
+                }
+            }
+            "-e" | "--echo" => options.echo = Echo::Discard,
+            "-m" | "--model" => {
+                let name = args.next().unwrap_or_else(|| usage());
+                let model = control_table::model_named(&name)
+                    .unwrap_or_else(|| fail(format!("unknown model {name}")));
+                options.model = Some(model);
+            }
+            "-h" | "--help" => usage(),
+            _ => options.command.push(arg),
+        }
+    }
+    if options.command.is_empty() {
+        usage();
+    }
+    options
+}
+
+/// Model of device `id`, as reported by its model number, unless given
+fn model<C: Protocol<Serial, PV>, const PV: u8>(
+    dmx: &mut C,
+    id: u8,
+    given: Option<&'static Model>,
+) -> &'static Model {
+    if let Some(model) = given {
+        return model;
+    }
+    let model_number = u16::from_le_bytes(
+        dmx.read(id, 0)
+            .unwrap_or_else(|e| fail(format!("can't read the model number of {id}: {e}"))),
+    );
+    control_table::model(model_number).unwrap_or_else(|| {
+        fail(format!(
+            "unknown model number {model_number} for {id}, give its model with -m"
+        ))
+    })
+}
+
+fn register(model: &Model, name: &str) -> &'static Register {
+    model
+        .register(name)
+        .unwrap_or_else(|| fail(format!("no register {name} on {}", model.name)))
+}
+
+fn read<C: Protocol<Serial, PV>, const PV: u8>(
+    dmx: &mut C,
+    id: u8,
+    register: &Register,
+) -> Result<u32, dmx::protocol::Error<Serial>> {
+    Ok(match register.size {
+        1 => u32::from(dmx.read::<1>(id, register.address)?[0]),
+        2 => u32::from(u16::from_le_bytes(dmx.read(id, register.address)?)),
+        _ => u32::from_le_bytes(dmx.read(id, register.address)?),
+    })
+}
+
+/// Raw value of `value` for a write in `register`
+fn raw(register: &Register, value: i64) -> u32 {
+    if !register.writable {
+        fail(format!("{} is read-only", register.name));
+    }
+    register
+        .raw(value)
+        .unwrap_or_else(|| fail(format!("{value} does not fit in {} bytes", register.size)))
+}
+
+fn write<C: Protocol<Serial, PV>, const PV: u8>(
+    dmx: &mut C,
+    id: u8,
+    register: &Register,
+    value: i64,
+) {
+    let data = &raw(register, value).to_le_bytes()[..usize::from(register.size)];
+    match dmx.write::<6, 0>(id, register.address, data) {
+        Ok(status) => check(status),
+        Err(e) => fail(e),
+    }
+}
+
+/// Report the error of a Status Packet, if any
+fn check<const N: usize>(status: Option<StatusPacket<N>>) {
+    if let Some(error) = status.and_then(|status| status.error_code()) {
+        fail(format!("device error 0x{error:02X}"));
+    }
+}
+
+fn run<const PV: u8>(dmx: &mut Controller<Serial, DummyPin, PV, Delay>, options: &Options)
+where
+    Controller<Serial, DummyPin, PV, Delay>: Protocol<Serial, PV>,
+{
+    let command: Vec<&str> = options.command.iter().map(String::as_str).collect();
+    match command[..] {
+        ["scan"] => {
+            for id in 0..=MAX_ID {
+                match dmx.ping(id) {
+                    Ok(true) => {
+                        match dmx.read(id, 0).map(u16::from_le_bytes) {
+                            Ok(number) => match control_table::model(number) {
+                                Some(model) => println!("{id}: {} ({number})", model.name),
+                                None => println!("{id}: unknown model ({number})"),
+                            },
+                            Err(e) => println!("{id}: {e}"),
+                        };
+                    }
+                    Ok(false) => {}
+                    Err(e) => println!("{id}: {e}"),
+                }
+            }
+        }
+        ["ping", device] => match dmx.ping(id(device)) {
+            Ok(true) => println!("{device} answers"),
+            Ok(false) => fail(format!("{device} does not answer")),
+            Err(e) => fail(e),
+        },
+        ["read", device, name] => {
+            let device = id(device);
+            let register = register(model(dmx, device, options.model), name);
+            match read(dmx, device, register) {
+                Ok(value) => println!("{}: {value}", register.name),
+                Err(e) => fail(e),
+            }
+        }
+        ["write", device, name, value] => {
+            let device = id(device);
+            let register = register(model(dmx, device, options.model), name);
+            write(dmx, device, register, number(value));
+        }
+        ["stage", device, name, value] => {
+            let device = id(device);
+            let register = register(model(dmx, device, options.model), name);
+            dmx.stage(device, register, raw(register, number(value)))
+                .unwrap_or_else(|e| fail(e));
+        }
+        ["action"] => dmx.action(BROADCAST_ID).unwrap_or_else(|e| fail(e)),
+        ["action", device] => dmx.action(id(device)).unwrap_or_else(|e| fail(e)),
+        ["dump", device] => dump(dmx, id(device), options.model, false),
+        ["dump", device, "json"] => dump(dmx, id(device), options.model, true),
+        ["reboot", device] => dmx.reboot(id(device)).unwrap_or_else(|e| fail(e)),
+        ["factory-reset", device, ref mode @ ..] if mode.len() <= 1 => {
+            let mode = match mode.first().copied().unwrap_or("all") {
+                "all" => FactoryReset::All,
+                "except-id" => FactoryReset::ExceptId,
+                "except-id-baud" => FactoryReset::ExceptIdAndBaudRate,
+                _ => usage(),
+            };
+            dmx.factory_reset(id(device), mode)
+                .unwrap_or_else(|e| fail(e));
+        }
+        ["clear-multi-turn", device] => {
+            dmx.clear_multi_turn(id(device)).unwrap_or_else(|e| fail(e))
+        }
+        ["backup", device] => dmx
+            .backup_control_table(id(device))
+            .unwrap_or_else(|e| fail(e)),
+        ["restore", device] => dmx
+            .restore_control_table(id(device))
+            .unwrap_or_else(|e| fail(e)),
+        ["set-id", device, new_id] => match dmx.change_id(id(device), id(new_id)) {
+            Ok(()) => println!("{device} is now {new_id}"),
+            Err(e) => fail(e),
+        },
+        ["set-baud", device, baud_rate] => {
+            let baud_rate = u32::try_from(number(baud_rate))
+                .unwrap_or_else(|_| fail(format!("{baud_rate} is not a baud rate")));
+            match dmx.change_baud(id(device), baud_rate) {
+                Ok(()) => println!("{device} is now at {baud_rate} bps"),
+                Err(e) => fail(e),
+            }
+        }
+        ["capture", file, ref devices @ ..] if !devices.is_empty() => {
+            let mut config = Config::new();
+            for &device in devices {
+                let device = id(device);
+                let model = model(dmx, device, options.model);
+                let captured = DeviceConfig::capture(dmx, device, model)
+                    .unwrap_or_else(|e| fail(format!("can't capture {device}: {e}")));
+                config.insert(captured).unwrap_or_else(|e| fail(e));
+            }
+            save(&config, file);
+        }
+        ["apply", file] => {
+            let config = load(file);
+            for device in &config.devices {
+                let model = model(dmx, device.id, None);
+                if model != device.model {
+                    fail(format!(
+                        "{} is a {}, not a {}",
+                        device.id, model.name, device.model.name
+                    ));
+                }
+                match device.apply(dmx) {
+                    Ok(written) => println!("{}: {written} values written", device.id),
+                    Err(e) => fail(format!("can't configure {}: {e}", device.id)),
+                }
+            }
+        }
+        ["check", file] => {
+            let config = load(file);
+            let mut drift = false;
+            for device in &config.devices {
+                match device.diff(dmx) {
+                    Ok(diff) => {
+                        drift |= !diff.is_empty();
+                        print!("{diff}");
+                    }
+                    Err(e) => fail(format!("can't check {}: {e}", device.id)),
+                }
+            }
+            if drift {
+                process::exit(1);
+            }
+        }
+        _ => usage(),
+    }
+}
+
+fn save(config: &Config, file: &str) {
+    let written = if file.ends_with(".toml") {
+        fs::write(file, config.to_toml())
+    } else {
+        let mut buffer = [0; 4096];
+        let size = config.to_bytes(&mut buffer).unwrap_or_else(|e| fail(e));
+        fs::write(file, &buffer[..size])
+    };
+    written.unwrap_or_else(|e| fail(format!("can't write {file}: {e}")));
+}
+
+fn load(file: &str) -> Config {
+    let bytes = fs::read(file).unwrap_or_else(|e| fail(format!("can't read {file}: {e}")));
+    let config = if file.ends_with(".toml") {
+        let toml = String::from_utf8(bytes).unwrap_or_else(|e| fail(format!("{file}: {e}")));
+        Config::from_toml(&toml)
+    } else {
+        Config::from_bytes(&bytes)
+    };
+    config.unwrap_or_else(|e| fail(format!("{file}: {e}")))
+}
+
+/// Show the whole control table of device `id`
+fn dump<C: Protocol<Serial, PV>, const PV: u8>(
+    dmx: &mut C,
+    id: u8,
+    given: Option<&'static Model>,
+    json: bool,
+) {
+    let model = model(dmx, id, given);
+    match Snapshot::read(dmx, id, model) {
+        Ok(snapshot) if json => println!("{}", snapshot.to_json()),
+        Ok(snapshot) => print!("{snapshot}"),
+        Err(e) => fail(e),
+    }
+}
+
+fn main() {
+    let options = options();
+    let port = serialport::new(&options.path, options.baudrate).timeout(Duration::from_millis(100));
+    let serial =
+        Serial::new(port).unwrap_or_else(|e| fail(format!("can't open {}: {e}", options.path)));
+    let timing = Timing {
+        latency: 20_000, // USB adapters can be slow
+        ..Timing::new(options.baudrate)
+    };
+    let direction = DummyPin::new_low();
+    if options.protocol == 1 {
+        let mut dmx = Controller::new_1(serial, direction, options.echo).with_timing(Delay, timing);
+        run(&mut dmx, &options);
+    } else {
+        let mut dmx = Controller::new_2(serial, direction, options.echo).with_timing(Delay, timing);
+        run(&mut dmx, &options);
+    }
+}
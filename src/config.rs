@@ -0,0 +1,625 @@
+//! Register values to set on devices, e.g. the EEPROM setup of a replacement servo
+//!
+//! A configuration is saved in a compact binary format, or under `std` as TOML,
+//! with a table per ID, the model of the device, and its register values by name:
+//!
+//! ```toml
+//! [1]
+//! model = "xl430w250"
+//! baud_rate = 3
+//! return_delay_time = 0
+//! ```
+
+use crate::admin;
+use crate::control_table::{self, Model, Register};
+use crate::protocol::{Error, Protocol};
+use crate::snapshot::Snapshot;
+use core::fmt;
+use embedded_io::{Read, ReadReady, Write};
+use heapless::Vec;
+
+#[cfg(feature = "std")]
+use crate::protocol::MAX_ID;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Most devices of a configuration
+pub const MAX_DEVICES: usize = 32;
+
+/// Most register values of a device
+pub const MAX_SETTINGS: usize = 48;
+
+/// Start of the binary format, and its version
+const MAGIC: [u8; 4] = *b"DMX\x01";
+
+/// Why a configuration is invalid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    UnknownModel { id: u8 },
+    UnknownRegister { id: u8 },
+    ReadOnly { id: u8, address: u16 },
+    OutOfRange { id: u8, address: u16 },
+    TooMany,  // more than MAX_DEVICES devices or MAX_SETTINGS values
+    TooSmall, // the buffer can't hold the binary format
+    Binary,   // not the binary format
+    Syntax { line: usize },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownModel { id } => write!(f, "unknown model for device {id}"),
+            Self::UnknownRegister { id } => write!(f, "unknown register for device {id}"),
+            Self::ReadOnly { id, address } => {
+                write!(f, "register {address} of device {id} is read-only")
+            }
+            Self::OutOfRange { id, address } => {
+                write!(
+                    f,
+                    "value out of range for register {address} of device {id}"
+                )
+            }
+            Self::TooMany => f.write_str("too many devices or values"),
+            Self::TooSmall => f.write_str("buffer too small"),
+            Self::Binary => f.write_str("not a binary configuration"),
+            Self::Syntax { line } => write!(f, "syntax error on line {line}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConfigError {}
+
+/// The raw value of a register
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Setting {
+    pub register: &'static Register,
+    pub value: u32,
+}
+
+/// Register values of device `id`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceConfig {
+    pub id: u8,
+    pub model: &'static Model,
+    pub settings: Vec<Setting, MAX_SETTINGS>,
+}
+
+impl DeviceConfig {
+    pub const fn new(id: u8, model: &'static Model) -> Self {
+        Self {
+            id,
+            model,
+            settings: Vec::new(),
+        }
+    }
+
+    /// Set register `name` to `value`, checking that it is writable and that the value fits
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), ConfigError> {
+        let id = self.id;
+        let register = self
+            .model
+            .register(name)
+            .ok_or(ConfigError::UnknownRegister { id })?;
+        let raw = register.raw(value).ok_or(ConfigError::OutOfRange {
+            id,
+            address: register.address,
+        })?;
+        self.set_raw(register, raw)
+    }
+
+    fn set_raw(&mut self, register: &'static Register, value: u32) -> Result<(), ConfigError> {
+        let id = self.id;
+        let address = register.address;
+        if !register.writable {
+            return Err(ConfigError::ReadOnly { id, address });
+        }
+        if register.raw(i64::from(value)).is_none() {
+            return Err(ConfigError::OutOfRange { id, address });
+        }
+        let setting = Setting { register, value };
+        match self
+            .settings
+            .iter_mut()
+            .find(|setting| setting.register.address == address)
+        {
+            Some(existing) => *existing = setting,
+            None => self
+                .settings
+                .push(setting)
+                .map_err(|_| ConfigError::TooMany)?,
+        }
+        Ok(())
+    }
+
+    /// Read the writable EEPROM registers of device `id`
+    pub fn capture<P, Serial, const PROTOCOL_VERSION: u8>(
+        dmx: &mut P,
+        id: u8,
+        model: &'static Model,
+    ) -> Result<Self, Error<Serial>>
+    where
+        P: Protocol<Serial, PROTOCOL_VERSION>,
+        Serial: Read + ReadReady + Write,
+    {
+        let snapshot = Snapshot::read(dmx, id, model)?;
+        let mut config = Self::new(id, model);
+        for value in &snapshot.values {
+            if value.register.writable && model.eeprom(value.register) {
+                let setting = Setting {
+                    register: value.register,
+                    value: value.raw,
+                };
+                config.settings.push(setting).map_err(|_| Error::TooSmall)?;
+            }
+        }
+        Ok(config)
+    }
+
+    /// Compare the values of device `id` with this configuration
+    pub fn diff<P, Serial, const PROTOCOL_VERSION: u8>(
+        &self,
+        dmx: &mut P,
+    ) -> Result<Diff, Error<Serial>>
+    where
+        P: Protocol<Serial, PROTOCOL_VERSION>,
+        Serial: Read + ReadReady + Write,
+    {
+        let mut diff = Diff {
+            id: self.id,
+            mismatches: Vec::new(),
+        };
+        for setting in &self.settings {
+            let register = setting.register;
+            let current = dmx.read_vec::<4>(self.id, register.address, u16::from(register.size))?;
+            let mut actual = [0; 4];
+            actual[..current.len()].copy_from_slice(&current);
+            let actual = u32::from_le_bytes(actual);
+            if actual != setting.value {
+                let mismatch = Mismatch {
+                    register,
+                    expected: setting.value,
+                    actual,
+                };
+                diff.mismatches
+                    .push(mismatch)
+                    .map_err(|_| Error::TooSmall)?;
+            }
+        }
+        Ok(diff)
+    }
+
+    /// Write the values which differ on the device, and return how many were written
+    ///
+    /// Torque is disabled for EEPROM writes, then enabled again.
+    /// `id` then `baud_rate` are written last: torque stays disabled after a new baud rate,
+    /// as the device then can't hear us. A new `id` must be free, which needs timeouts to
+    /// find out.
+    pub fn apply<P, Serial, const PROTOCOL_VERSION: u8>(
+        &self,
+        dmx: &mut P,
+    ) -> Result<usize, Error<Serial>>
+    where
+        P: Protocol<Serial, PROTOCOL_VERSION>,
+        Serial: Read + ReadReady + Write,
+    {
+        let mut id = self.id;
+        let diff = self.diff(dmx)?;
+        let pending: Vec<Setting, MAX_SETTINGS> = diff
+            .mismatches
+            .iter()
+            .map(|mismatch| Setting {
+                register: mismatch.register,
+                value: mismatch.expected,
+            })
+            .collect();
+
+        // don't give the ID of another device, before writing anything
+        if let Some(setting) = pending.iter().find(|setting| setting.register.name == "id") {
+            admin::check_free(dmx, setting.value as u8)?;
+        }
+
+        let torque_enable = self.model.register("torque_enable");
+        let mut torque = false;
+        if let Some(torque_enable) = torque_enable {
+            if pending
+                .iter()
+                .any(|setting| self.model.eeprom(setting.register))
+            {
+                torque = dmx.read::<1>(id, torque_enable.address)? != [0];
+                if torque {
+                    dmx.write::<3, 0>(id, torque_enable.address, &[0])?;
+                }
+            }
+        }
+
+        let mut new_id = None;
+        let mut baud_rate = None;
+        for setting in &pending {
+            match setting.register.name {
+                "id" => new_id = Some(setting),
+                "baud_rate" => baud_rate = Some(setting),
+                _ => write(dmx, id, setting)?,
+            }
+        }
+        if let Some(setting) = new_id {
+            let new = setting.value as u8;
+            admin::write_id(dmx, id, setting.register.address, new)?;
+            // the device keeps its status return level
+            let level = dmx.status_return_level(id);
+            dmx.set_status_return_level(new, level);
+            id = new;
+        }
+        if let (true, Some(torque_enable), None) = (torque, torque_enable, baud_rate) {
+            dmx.write::<3, 0>(id, torque_enable.address, &[1])?;
+        }
+        if let Some(setting) = baud_rate {
+            admin::write_baud_rate(dmx, id, setting.register, setting.value)?;
+        }
+        Ok(pending.len())
+    }
+}
+
+/// A register whose value is not the expected one
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub register: &'static Register,
+    pub expected: u32,
+    pub actual: u32,
+}
+
+/// Registers of device `id` which differ from its configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diff {
+    pub id: u8,
+    pub mismatches: Vec<Mismatch, MAX_SETTINGS>,
+}
+
+impl Diff {
+    /// Whether the device matches its configuration
+    pub fn is_empty(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// A line per mismatch: ID, register name and address, expected and actual values
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for mismatch in &self.mismatches {
+            let register = mismatch.register;
+            writeln!(
+                f,
+                "{}: {} ({}) expected {}, actual {}",
+                self.id, register.name, register.address, mismatch.expected, mismatch.actual
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn write<P, Serial, const PROTOCOL_VERSION: u8>(
+    dmx: &mut P,
+    id: u8,
+    setting: &Setting,
+) -> Result<(), Error<Serial>>
+where
+    P: Protocol<Serial, PROTOCOL_VERSION>,
+    Serial: Read + ReadReady + Write,
+{
+    let size = usize::from(setting.register.size);
+    let data = setting.value.to_le_bytes();
+    dmx.write::<6, 0>(id, setting.register.address, &data[..size])
+        .map(drop)
+}
+
+/// Register values of several devices
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub devices: Vec<DeviceConfig, MAX_DEVICES>,
+}
+
+impl Config {
+    pub const fn new() -> Self {
+        Self {
+            devices: Vec::new(),
+        }
+    }
+
+    /// Configuration of device `id`
+    pub fn device(&self, id: u8) -> Option<&DeviceConfig> {
+        self.devices.iter().find(|device| device.id == id)
+    }
+
+    /// Add a device, or replace its configuration
+    pub fn insert(&mut self, config: DeviceConfig) -> Result<(), ConfigError> {
+        match self
+            .devices
+            .iter_mut()
+            .find(|device| device.id == config.id)
+        {
+            Some(device) => *device = config,
+            None => self
+                .devices
+                .push(config)
+                .map_err(|_| ConfigError::TooMany)?,
+        }
+        Ok(())
+    }
+
+    /// Write the binary format in `buffer`, and return its size
+    ///
+    /// After a magic and version, each device is its ID, model number and number of values,
+    /// then each value is its address, size and little-endian bytes.
+    pub fn to_bytes(&self, buffer: &mut [u8]) -> Result<usize, ConfigError> {
+        let mut bytes = Bytes { buffer, len: 0 };
+        bytes.extend(&MAGIC)?;
+        bytes.extend(&[self.devices.len() as u8])?;
+        for device in &self.devices {
+            bytes.extend(&[device.id])?;
+            bytes.extend(&device.model.model_number.to_le_bytes())?;
+            bytes.extend(&[device.settings.len() as u8])?;
+            for setting in &device.settings {
+                let register = setting.register;
+                let size = usize::from(register.size);
+                bytes.extend(&register.address.to_le_bytes())?;
+                bytes.extend(&[register.size])?;
+                bytes.extend(&setting.value.to_le_bytes()[..size])?;
+            }
+        }
+        Ok(bytes.len)
+    }
+
+    /// Read and validate the binary format
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ConfigError> {
+        let mut bytes = bytes
+            .strip_prefix(&MAGIC[..])
+            .ok_or(ConfigError::Binary)?
+            .iter()
+            .copied();
+        let mut next = || bytes.next().ok_or(ConfigError::Binary);
+        let mut config = Self::new();
+        for _ in 0..next()? {
+            let id = next()?;
+            let model_number = u16::from_le_bytes([next()?, next()?]);
+            let model =
+                control_table::model(model_number).ok_or(ConfigError::UnknownModel { id })?;
+            let mut device = DeviceConfig::new(id, model);
+            for _ in 0..next()? {
+                let address = u16::from_le_bytes([next()?, next()?]);
+                let size = next()?;
+                let register = model
+                    .control_table
+                    .iter()
+                    .find(|register| register.address == address && register.size == size)
+                    .ok_or(ConfigError::UnknownRegister { id })?;
+                let mut value = [0; 4];
+                for byte in value.iter_mut().take(usize::from(size)) {
+                    *byte = next()?;
+                }
+                device.set_raw(register, u32::from_le_bytes(value))?;
+            }
+            config.insert(device)?;
+        }
+        Ok(config)
+    }
+
+    /// Read and validate the TOML format
+    ///
+    /// Each ID has one table, above MAX_ID is invalid, and one `model` before its values.
+    #[cfg(feature = "std")]
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        let mut config = Self::new();
+        let mut id = None; // of the current table
+        let mut device: Option<DeviceConfig> = None; // once its model is known
+        for (i, line) in toml.lines().enumerate() {
+            let syntax = ConfigError::Syntax { line: i + 1 };
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(table) = line.strip_prefix('[') {
+                if let Some(device) = device.take() {
+                    config.insert(device)?;
+                }
+                id = table
+                    .strip_suffix(']')
+                    .and_then(|id| id.trim().trim_matches('"').parse().ok())
+                    .filter(|&id| id <= MAX_ID && config.device(id).is_none());
+                id.ok_or(syntax)?;
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| syntax.clone())?;
+            let (key, value) = (key.trim().trim_matches('"'), value.trim());
+            let id = id.ok_or_else(|| syntax.clone())?;
+            if key == "model" {
+                if device.is_some() {
+                    return Err(syntax);
+                }
+                let name = value
+                    .strip_prefix('"')
+                    .and_then(|name| name.strip_suffix('"'))
+                    .ok_or(syntax)?;
+                let model =
+                    control_table::model_named(name).ok_or(ConfigError::UnknownModel { id })?;
+                device = Some(DeviceConfig::new(id, model));
+            } else {
+                let device = device.as_mut().ok_or(ConfigError::UnknownModel { id })?;
+                device.set(key, parse_integer(value).ok_or(syntax)?)?;
+            }
+        }
+        if let Some(device) = device {
+            config.insert(device)?;
+        }
+        Ok(config)
+    }
+
+    /// Write the TOML format, with the values of signed registers as negative numbers
+    #[cfg(feature = "std")]
+    pub fn to_toml(&self) -> std::string::String {
+        use core::fmt::Write;
+        let mut toml = std::string::String::new();
+        for (i, device) in self.devices.iter().enumerate() {
+            if i > 0 {
+                toml.push('\n');
+            }
+            let _ = writeln!(toml, "[{}]", device.id);
+            let _ = writeln!(toml, "model = \"{}\"", device.model.name);
+            for setting in &device.settings {
+                let register = setting.register;
+                let value = if device.model.signed(register) {
+                    let shift = 64 - 8 * u32::from(register.size);
+                    (i64::from(setting.value) << shift) >> shift
+                } else {
+                    i64::from(setting.value)
+                };
+                let _ = writeln!(toml, "{} = {}", register.name, value);
+            }
+        }
+        toml
+    }
+}
+
+/// Decimal or `0x` hexadecimal integer
+#[cfg(feature = "std")]
+fn parse_integer(value: &str) -> Option<i64> {
+    let (digits, negative) = match value.strip_prefix('-') {
+        Some(digits) => (digits, true),
+        None => (value, false),
+    };
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => i64::from_str_radix(hex, 16).ok()?,
+        None => digits.replace('_', "").parse().ok()?,
+    };
+    Some(if negative { -value } else { value })
+}
+
+/// Bytes written in a buffer
+struct Bytes<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl Bytes<'_> {
+    fn extend(&mut self, bytes: &[u8]) -> Result<(), ConfigError> {
+        let end = self.len + bytes.len();
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(ConfigError::TooSmall)?
+            .copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        let model = |name| control_table::model_named(name).unwrap();
+        let mut xl430 = DeviceConfig::new(1, model("xl430w250"));
+        xl430.set("baud_rate", 3).unwrap();
+        xl430.set("return_delay_time", 0).unwrap();
+        xl430.set("homing_offset", -5).unwrap();
+        xl430.set("max_position_limit", 3000).unwrap();
+        let mut ax12 = DeviceConfig::new(7, model("ax12a"));
+        ax12.set("baud_rate", 1).unwrap();
+        let mut config = Config::new();
+        config.insert(xl430).unwrap();
+        config.insert(ax12).unwrap();
+        config
+    }
+
+    #[test]
+    fn set() {
+        let mut device = DeviceConfig::new(1, control_table::model_named("xl320").unwrap());
+        assert_eq!(
+            device.set("model_number", 350),
+            Err(ConfigError::ReadOnly { id: 1, address: 0 })
+        );
+        assert_eq!(
+            device.set("id", 256),
+            Err(ConfigError::OutOfRange { id: 1, address: 3 })
+        );
+        assert_eq!(
+            device.set("no_such_register", 0),
+            Err(ConfigError::UnknownRegister { id: 1 })
+        );
+        device.set("id", 2).unwrap();
+        device.set("id", 3).unwrap();
+        assert_eq!(device.settings.len(), 1);
+        assert_eq!(device.settings[0].value, 3);
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let config = config();
+        let mut buffer = [0; 256];
+        let len = config.to_bytes(&mut buffer).unwrap();
+        assert_eq!(Config::from_bytes(&buffer[..len]), Ok(config));
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        let mut buffer = [0; 256];
+        let len = config().to_bytes(&mut buffer).unwrap();
+        assert_eq!(
+            config().to_bytes(&mut buffer[..len - 1]),
+            Err(ConfigError::TooSmall)
+        );
+        assert_eq!(
+            Config::from_bytes(&buffer[..len - 1]),
+            Err(ConfigError::Binary)
+        );
+        assert_eq!(
+            Config::from_bytes(&buffer[1..len]),
+            Err(ConfigError::Binary)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn toml_roundtrip() {
+        let config = config();
+        let toml = config.to_toml();
+        assert!(toml.contains("\nhoming_offset = -5\n"));
+        assert_eq!(Config::from_toml(&toml), Ok(config));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_toml() {
+        let toml = "# robot\n[1]\nmodel = \"xl430w250\"\nbaud_rate = 3 # 1 Mbps\nhoming_offset = -5\n\n[\"7\"]\nmodel = \"ax12a\"\nbaud-rate = 0x01\n";
+        assert_eq!(Config::from_toml(toml), Ok(config_subset()));
+        assert_eq!(
+            Config::from_toml("[1]\nbaud_rate = 3\n"),
+            Err(ConfigError::UnknownModel { id: 1 })
+        );
+        assert_eq!(
+            Config::from_toml("[1]\nmodel = \"xl430w250\"\nbaud_rate\n"),
+            Err(ConfigError::Syntax { line: 3 })
+        );
+        assert_eq!(
+            Config::from_toml("[253]\nmodel = \"xl430w250\"\n"),
+            Err(ConfigError::Syntax { line: 1 })
+        );
+        assert_eq!(
+            Config::from_toml("[1]\nmodel = \"xl430w250\"\nid = 2\nmodel = \"xl430w250\"\n"),
+            Err(ConfigError::Syntax { line: 4 })
+        );
+        assert_eq!(
+            Config::from_toml("[1]\nmodel = \"ax12a\"\n[2]\nmodel = \"ax12a\"\n[1]\n"),
+            Err(ConfigError::Syntax { line: 5 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    fn config_subset() -> Config {
+        let mut config = config();
+        config.devices[0]
+            .settings
+            .retain(|setting| matches!(setting.register.name, "baud_rate" | "homing_offset"));
+        config
+    }
+}
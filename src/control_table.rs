@@ -0,0 +1,213 @@
+//! Control tables of the devices, to find registers by name at runtime
+
+pub use crate::generated::MODELS;
+
+/// A register of a control table
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Register {
+    pub name: &'static str, // as in the generated getters, e.g. `goal_position`
+    pub address: u16,
+    pub size: u8, // in bytes
+    pub writable: bool,
+    pub description: &'static str,
+    pub initial: &'static str, // initial value, from the documentation
+}
+
+impl Register {
+    /// Raw value of `value`, if it fits in this register, as unsigned or two's complement
+    pub fn raw(&self, value: i64) -> Option<u32> {
+        let bits = 8 * u32::from(self.size);
+        let fits = if value < 0 {
+            value >= -(1 << (bits - 1))
+        } else {
+            value < 1 << bits
+        };
+        // truncating is the two's complement on `size` bytes
+        fits.then(|| (value & ((1 << bits) - 1)) as u32)
+    }
+}
+
+/// Physical unit of a register
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Unit {
+    pub scale: f32, // per raw unit
+    pub symbol: &'static str,
+    pub signed: bool, // raw values are two's complement
+}
+
+/// A device model, and its control table
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Model {
+    pub name: &'static str, // as the generated module, e.g. `xl320`
+    pub model_number: u16,
+    pub control_table: &'static [Register],
+}
+
+impl Model {
+    /// Register named `name`, with `-` or `_` between words
+    pub fn register(&self, name: &str) -> Option<&'static Register> {
+        self.control_table.iter().find(|register| {
+            register.name.len() == name.len()
+                && register
+                    .name
+                    .bytes()
+                    .zip(name.bytes())
+                    .all(|(r, n)| r == n.to_ascii_lowercase() || (r == b'_' && n == b'-'))
+        })
+    }
+
+    /// Whether a register is in the EEPROM area, which can be written only without torque
+    pub fn eeprom(&self, register: &Register) -> bool {
+        match self.register("torque_enable") {
+            Some(torque_enable) => register.address < torque_enable.address,
+            None => false,
+        }
+    }
+
+    /// Value of the `baud_rate` register for this baud rate, if the model supports it
+    pub fn baud_rate_value(&self, baud_rate: u32) -> Option<u32> {
+        const XL320: [u32; 4] = [9_600, 57_600, 115_200, 1_000_000];
+        const PROTOCOL_2: [u32; 9] = [
+            9_600, 57_600, 115_200, 1_000_000, 2_000_000, 3_000_000, 4_000_000, 4_500_000,
+            10_500_000, // PRO series only
+        ];
+        const MX: [(u32, u32); 3] = [(250, 2_250_000), (251, 2_500_000), (252, 3_000_000)];
+        let name = self.name;
+        let index = |table: &[u32]| table.iter().position(|&b| b == baud_rate).map(|i| i as u32);
+        if name == "xl320" {
+            index(&XL320)
+        } else if self.register("baud_rate")?.address == 4 {
+            // Protocol 1 tables: 2 Mbps / (value + 1), within 3%
+            if name.starts_with("mx") {
+                if let Some(&(value, _)) = MX.iter().find(|&&(_, b)| b == baud_rate) {
+                    return Some(value);
+                }
+            }
+            let value = (2_000_000 + baud_rate / 2) / baud_rate.max(1);
+            let actual = 2_000_000 / value.max(1);
+            let close = actual.abs_diff(baud_rate) * 100 <= 3 * baud_rate;
+            (close && (1..=255).contains(&value)).then(|| value - 1)
+        } else if name.starts_with('x') || name.starts_with("mx") {
+            index(&PROTOCOL_2[..8])
+        } else {
+            index(&PROTOCOL_2)
+        }
+    }
+
+    /// Whether the raw values of a register are two's complement: those of 4 bytes, e.g.
+    /// positions and offsets, and the currents and PWM of Protocol 2
+    pub fn signed(&self, register: &Register) -> bool {
+        let protocol_2 = self
+            .register("baud_rate")
+            .is_some_and(|baud_rate| baud_rate.address != 4);
+        let name = register.name;
+        register.size == 4
+            || (register.size == 2
+                && protocol_2
+                && (name.ends_with("current") || name.ends_with("pwm")))
+    }
+
+    /// Unit of a register, for the few whose unit is known
+    pub fn unit(&self, register: &Register) -> Option<Unit> {
+        let unit = |scale, symbol| Unit {
+            scale,
+            symbol,
+            signed: self.signed(register),
+        };
+        let name = register.name;
+        if name.contains("voltage") {
+            Some(unit(0.1, "V"))
+        } else if name.contains("temperature") {
+            Some(unit(1., "°C"))
+        } else if name.ends_with("position") || name.ends_with("position_limit") {
+            self.position_scale().map(|scale| unit(scale, "°"))
+        } else if name.ends_with("velocity") || name == "velocity_limit" {
+            // Protocol 2 tables of the MX and X series
+            (self.name.starts_with('x') || self.name.starts_with("mx")).then(|| unit(0.229, "rpm"))
+        } else {
+            None
+        }
+    }
+
+    /// Degrees per position unit
+    fn position_scale(&self) -> Option<f32> {
+        let name = self.name;
+        if ["ax", "rx", "dx"]
+            .iter()
+            .any(|serie| name.starts_with(serie))
+            || name == "xl320"
+        {
+            Some(0.29)
+        } else if name.starts_with("ex") {
+            Some(0.06)
+        } else if name.starts_with("mx") || name.starts_with('x') {
+            Some(0.088)
+        } else {
+            None // the unit of PRO series depends on the model
+        }
+    }
+}
+
+/// Model with this model number, as read at address 0
+pub fn model(model_number: u16) -> Option<&'static Model> {
+    MODELS
+        .iter()
+        .find(|model| model.model_number == model_number)
+}
+
+/// Model named as its generated module, e.g. `xl320`
+pub fn model_named(name: &str) -> Option<&'static Model> {
+    MODELS
+        .iter()
+        .find(|model| model.name.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baud_rate_value(name: &str, baud_rate: u32) -> Option<u32> {
+        model_named(name).unwrap().baud_rate_value(baud_rate)
+    }
+
+    #[test]
+    fn signed() {
+        let signed = |model, name| {
+            let model = model_named(model).unwrap();
+            model.signed(model.register(name).unwrap())
+        };
+        assert!(signed("xl430w250", "homing_offset"));
+        assert!(signed("xm430w350", "goal_current"));
+        assert!(!signed("xl430w250", "return_delay_time"));
+        assert!(!signed("ax12a", "present_load"));
+        assert!(!signed("xl320", "goal_position"));
+    }
+
+    #[test]
+    fn protocol_1_baud_rates() {
+        assert_eq!(baud_rate_value("ax12a", 1_000_000), Some(1));
+        assert_eq!(baud_rate_value("ax12a", 57_600), Some(34));
+        assert_eq!(baud_rate_value("ax12a", 9_600), Some(207));
+        assert_eq!(baud_rate_value("ax12a", 3_000_000), None);
+        assert_eq!(baud_rate_value("ax12a", 0), None);
+    }
+
+    #[test]
+    fn mx_baud_rates() {
+        assert_eq!(baud_rate_value("mx28", 2_250_000), Some(250));
+        assert_eq!(baud_rate_value("mx28", 3_000_000), Some(252));
+        assert_eq!(baud_rate_value("mx28", 1_000_000), Some(1));
+        assert_eq!(baud_rate_value("mx282", 4_000_000), Some(6));
+    }
+
+    #[test]
+    fn protocol_2_baud_rates() {
+        assert_eq!(baud_rate_value("xl320", 1_000_000), Some(3));
+        assert_eq!(baud_rate_value("xl320", 2_000_000), None);
+        assert_eq!(baud_rate_value("xl430w250", 57_600), Some(1));
+        assert_eq!(baud_rate_value("xl430w250", 4_500_000), Some(7));
+        assert_eq!(baud_rate_value("xl430w250", 10_500_000), None);
+        assert_eq!(baud_rate_value("h54200s500r", 10_500_000), Some(8));
+        assert_eq!(baud_rate_value("h54200s500r", 38_400), None);
+    }
+}
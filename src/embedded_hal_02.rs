@@ -0,0 +1,157 @@
+//! Adapters for HALs which still implement [`embedded-hal` 0.2](https://docs.rs/embedded-hal/0.2.7) traits:
+//! [`serial::Read`](https://docs.rs/embedded-hal/0.2.7/embedded_hal/serial/trait.Read.html) and
+//! [`serial::Write`](https://docs.rs/embedded-hal/0.2.7/embedded_hal/serial/trait.Write.html)
+//! into [`embedded_io`] traits, `digital::v2::OutputPin` and `blocking::delay::DelayUs`
+//! into their [`embedded_hal`] 1.0 counterparts.
+
+use core::fmt;
+use embedded_hal_02::{blocking::delay::DelayUs, digital::v2, serial};
+use nb::block;
+
+/// Error of an `embedded-hal` 0.2 serial, which may have different errors for reads and writes
+#[derive(Debug)]
+pub enum SerialError<R, W> {
+    Read(R),
+    Write(W),
+}
+
+impl<R: fmt::Debug, W: fmt::Debug> embedded_io::Error for SerialError<R, W> {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        embedded_io::ErrorKind::Other
+    }
+}
+
+/// Error of an `embedded-hal` 0.2 pin
+#[derive(Debug)]
+pub struct Error<E>(pub E);
+
+impl<E: fmt::Debug> embedded_hal::digital::Error for Error<E> {
+    fn kind(&self) -> embedded_hal::digital::ErrorKind {
+        embedded_hal::digital::ErrorKind::Other
+    }
+}
+
+/// `embedded-hal` 0.2 serial, with room for a byte received while checking if one is ready
+#[derive(Debug)]
+pub struct Serial<S> {
+    pub serial: S,
+    byte: Option<u8>,
+}
+
+impl<S> Serial<S> {
+    pub const fn new(serial: S) -> Self {
+        Self { serial, byte: None }
+    }
+}
+
+impl<S> embedded_io::ErrorType for Serial<S>
+where
+    S: serial::Read<u8> + serial::Write<u8>,
+    <S as serial::Read<u8>>::Error: fmt::Debug,
+    <S as serial::Write<u8>>::Error: fmt::Debug,
+{
+    type Error = SerialError<<S as serial::Read<u8>>::Error, <S as serial::Write<u8>>::Error>;
+}
+
+impl<S> embedded_io::Read for Serial<S>
+where
+    S: serial::Read<u8> + serial::Write<u8>,
+    <S as serial::Read<u8>>::Error: fmt::Debug,
+    <S as serial::Write<u8>>::Error: fmt::Debug,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        buf[0] = match self.byte.take() {
+            Some(byte) => byte,
+            None => block!(self.serial.read()).map_err(SerialError::Read)?,
+        };
+        let mut n = 1;
+        for b in &mut buf[1..] {
+            match self.serial.read() {
+                Ok(byte) => *b = byte,
+                Err(nb::Error::WouldBlock) => break,
+                Err(nb::Error::Other(e)) => return Err(SerialError::Read(e)),
+            }
+            n += 1;
+        }
+        Ok(n)
+    }
+}
+
+impl<S> embedded_io::ReadReady for Serial<S>
+where
+    S: serial::Read<u8> + serial::Write<u8>,
+    <S as serial::Read<u8>>::Error: fmt::Debug,
+    <S as serial::Write<u8>>::Error: fmt::Debug,
+{
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        if self.byte.is_none() {
+            match self.serial.read() {
+                Ok(byte) => self.byte = Some(byte),
+                Err(nb::Error::WouldBlock) => {}
+                Err(nb::Error::Other(e)) => return Err(SerialError::Read(e)),
+            }
+        }
+        Ok(self.byte.is_some())
+    }
+}
+
+impl<S> embedded_io::Write for Serial<S>
+where
+    S: serial::Read<u8> + serial::Write<u8>,
+    <S as serial::Read<u8>>::Error: fmt::Debug,
+    <S as serial::Write<u8>>::Error: fmt::Debug,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        for &b in buf {
+            block!(self.serial.write(b)).map_err(SerialError::Write)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        block!(self.serial.flush()).map_err(SerialError::Write)
+    }
+}
+
+/// `embedded-hal` 0.2 output pin
+#[derive(Debug)]
+pub struct Pin<P>(pub P);
+
+impl<P> embedded_hal::digital::ErrorType for Pin<P>
+where
+    P: v2::OutputPin,
+    P::Error: fmt::Debug,
+{
+    type Error = Error<P::Error>;
+}
+
+impl<P> embedded_hal::digital::OutputPin for Pin<P>
+where
+    P: v2::OutputPin,
+    P::Error: fmt::Debug,
+{
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.0.set_low().map_err(Error)
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.0.set_high().map_err(Error)
+    }
+}
+
+/// `embedded-hal` 0.2 delay, with µs resolution
+#[derive(Debug)]
+pub struct Delay<D>(pub D);
+
+impl<D: DelayUs<u32>> embedded_hal::delay::DelayNs for Delay<D> {
+    fn delay_ns(&mut self, ns: u32) {
+        self.0.delay_us(ns.div_ceil(1_000));
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        self.0.delay_us(us);
+    }
+}
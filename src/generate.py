@@ -4,39 +4,230 @@ Generate getters and setters for a dynamixel motor from a control table TSV copy
 from the documentation website
 """
 
+import re
 from pathlib import Path
 from subprocess import run
 
 HEAD = """
-use crate::protocol::{Controller, Instruction, Protocol, StatusPacket, Error};
-use embedded_hal::{digital::v2::OutputPin, serial};
-use heapless::Vec;
+use crate::control_table::Register;
+use crate::protocol::{Controller, Protocol, StatusPacket, Error};
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+use embedded_io::{Read, ReadReady, Write};
 
 
 pub trait MOTOR<Serial, const PROTOCOL_VERSION: u8>: Protocol<Serial, PROTOCOL_VERSION>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
 {
 """
 
 TAIL = """
 }
 
-impl<Serial, Direction> MOTOR<Serial, 1> for Controller<Serial, Direction, 1>
+impl<Serial, Direction, Delay> MOTOR<Serial, 1> for Controller<Serial, Direction, 1, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
     Direction: OutputPin,
+    Delay: DelayNs,
 {
 }
 
-impl<Serial, Direction> MOTOR<Serial, 2> for Controller<Serial, Direction, 2>
+impl<Serial, Direction, Delay> MOTOR<Serial, 2> for Controller<Serial, Direction, 2, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
     Direction: OutputPin,
+    Delay: DelayNs,
 {
 }
 """
 
+ASYNC_HEAD = """
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait MOTORAsync<Serial, const PROTOCOL_VERSION: u8>:
+    crate::protocol_async::AsyncProtocol<Serial, PROTOCOL_VERSION>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+{
+"""
+
+ASYNC_TAIL = """
+}
+
+#[cfg(feature = "async")]
+impl<Serial, Direction, Delay> MOTORAsync<Serial, 1> for Controller<Serial, Direction, 1, Delay>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+    Direction: OutputPin,
+    Delay: embedded_hal_async::delay::DelayNs,
+{
+}
+
+#[cfg(feature = "async")]
+impl<Serial, Direction, Delay> MOTORAsync<Serial, 2> for Controller<Serial, Direction, 2, Delay>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+    Direction: OutputPin,
+    Delay: embedded_hal_async::delay::DelayNs,
+{
+}
+"""
+
+
+def register_name(data_name):
+    return (
+        data_name.split("]")[0][1:]
+        .replace(" ", "_")
+        .replace("(Shadow)", "_shadow")
+        .lower()
+    )
+
+
+def rust_str(text):
+    escaped = str(text).replace("\\", "\\\\").replace('"', '\\"')
+    return f'"{escaped}"'
+
+
+def model_number(rows):
+    """Model number from its initial value, if documented"""
+    for address, size, data_name, description, access, initial_value in rows:
+        if register_name(data_name) == "model_number":
+            number = str(initial_value).replace(",", "")
+            return int(number) if number.isdigit() else None
+    return None
+
+
+def numbered(data_name):
+    """(family, index) of a register numbered like `indirect_address_3`, if it is"""
+    match = re.fullmatch(r"(.*)_(\d+)", register_name(data_name))
+    return (match[1], int(match[2])) if match else None
+
+
+def renumber(text, old, new):
+    """`text` with its last number `old` replaced by `new`"""
+    matches = list(re.finditer(rf"(?<!\d){old}(?!\d)", str(text)))
+    if not matches:
+        return text
+    last = matches[-1]
+    return f"{text[:last.start()]}{new}{text[last.end():]}"
+
+
+def interpolate(first, last, k, gap):
+    """Initial value of the k-th elided register between two documented ones"""
+    first, last = str(first).replace(",", ""), str(last).replace(",", "")
+    if first.isdigit() and last.isdigit() and (int(last) - int(first)) % gap == 0:
+        return str(int(first) + k * (int(last) - int(first)) // gap)
+    return "-"
+
+
+def expand(rows):
+    """Rows with the ranges elided in the documentation filled in, e.g. `...` between
+    `indirect_address_3` and `indirect_address_26`, and the families of those ranges"""
+    expanded, families = [], set()
+    for row, after in zip(rows, rows[1:] + [None]):
+        expanded.append(row)
+        if after is None:
+            continue
+        first, last = numbered(row[2]), numbered(after[2])
+        if first is None or last is None or first[0] != last[0]:
+            continue
+        gap = last[1] - first[1]
+        address, size = int(row[0]), int(row[1])
+        if gap < 2 or int(after[0]) - address != gap * size:
+            continue
+        families.add(first[0])
+        for k in range(1, gap):
+            n = first[1] + k
+            expanded.append(
+                (
+                    str(address + k * size),
+                    row[1],
+                    renumber(row[2], first[1], n),
+                    renumber(row[3], first[1], n),
+                    row[4],
+                    interpolate(row[5], after[5], k, gap),
+                )
+            )
+    return expanded, families
+
+
+def runs(rows, family):
+    """Consecutive registers of a family, as (first index, last index, first row)"""
+    found = []
+    for row in rows:
+        number = numbered(row[2])
+        if number is None or number[0] != family:
+            continue
+        n = number[1]
+        if found:
+            first, last, first_row = found[-1]
+            address = int(first_row[0]) + (n - first) * int(first_row[1])
+            if n == last + 1 and int(row[0]) == address:
+                found[-1] = (first, n, first_row)
+                continue
+        found.append((n, n, row))
+    return found
+
+
+def generate_indexed(family, family_runs, *, motor, out, asynchronous=False):
+    """Getter and setter of the N-th register of a family, instead of one per register"""
+    fn = "async fn" if asynchronous else "fn"
+    aw = ".await" if asynchronous else ""
+    first, _, row = family_runs[0]
+    last = family_runs[-1][1]
+    size = int(row[1])
+    description = renumber(row[3], first, "N")
+    arms = []
+    for start, end, start_row in family_runs:
+        offset = f"(n - {start})" if size == 1 else f"{size} * (n - {start})"
+        arms.append(f"    {start}..={end} => {int(start_row[0])} + {offset},")
+    address = [
+        "let address = match n {",
+        *arms,
+        "    _ => return Err(Error::UnknownRegister),",
+        "};",
+    ]
+    lines = [
+        f"/// {description}, for N in {first}..={last}",
+        f"{fn} get_{motor}_{family}(&mut self, id: u8, n: u16) -> "
+        f"Result<u{size * 8}, Error<Serial>> {{",
+        *address,
+        f"    Ok(u{size * 8}::from_le_bytes(self.read(id, address){aw}?))",
+        "}",
+    ]
+    if row[4] == "RW":
+        lines += [
+            f"{fn} set_{motor}_{family}(&mut self, id: u8, n: u16, params: u{size * 8}) -> "
+            f"Result<Option<StatusPacket<{size}>>, Error<Serial>> {{",
+            *address,
+            f"    self.write::<{2 + size}, {size}>"
+            f"(id, address, &params.to_le_bytes()){aw}",
+            "}",
+        ]
+    for line in lines:
+        print(line, file=out)
+
+
+def control_table(rows, out):
+    number = model_number(rows)
+    if number is not None:
+        print(f"pub const MODEL_NUMBER: u16 = {number};", file=out)
+    print("pub const CONTROL_TABLE: &[Register] = &[", file=out)
+    for address, size, data_name, description, access, initial_value in rows:
+        lines = [
+            "Register {",
+            f"    name: {rust_str(register_name(data_name))},",
+            f"    address: {int(address)},",
+            f"    size: {int(size)},",
+            f"    writable: {'true' if access == 'RW' else 'false'},",
+            f"    description: {rust_str(description)},",
+            f"    initial: {rust_str(initial_value)},",
+            "},",
+        ]
+        for line in lines:
+            print(line, file=out)
+    print("];", file=out)
+
 
 def generate(
     address,
@@ -50,62 +241,115 @@ def generate(
     *,
     motor,
     out,
+    asynchronous=False,
 ):
-    address = (int(address) & 0xFF, int(address) >> 8)
+    fn = "async fn" if asynchronous else "fn"
+    aw = ".await" if asynchronous else ""
+    address = int(address)
     size = int(size)
-    size_t = (size & 0xFF, size >> 8)
-    data_name = (
-        data_name.split("]")[0][1:]
-        .replace(" ", "_")
-        .replace("(Shadow)", "_shadow")
-        .lower()
-    )
+    data_name = register_name(data_name)
+    if data_name == "status_return_level":
+        # keep track of which instructions will get a status packet
+        lines = [
+            f"/// {description} (initial: {initial_value})",
+            f"{fn} get_{motor}_{data_name}(&mut self, id: u8) -> "
+            f"Result<u{size * 8}, Error<Serial>> {{",
+            f"    let level = u{size * 8}::from_le_bytes("
+            f"self.read(id, {address}){aw}?);",
+            "    self.set_status_return_level(id, level.into());",
+            "    Ok(level)",
+            "}",
+            f"{fn} set_{motor}_{data_name}(&mut self, id: u8, params: u{size * 8}) -> "
+            f"Result<Option<StatusPacket<{size}>>, Error<Serial>> {{",
+            f"    let status = self.write::<{2 + size}, {size}>"
+            f"(id, {address}, &params.to_le_bytes()){aw}?;",
+            "    self.set_status_return_level(id, params.into());",
+            "    Ok(status)",
+            "}",
+        ]
+        for line in lines:
+            print(line, file=out)
+        return
     lines = [
         f"/// {description} (initial: {initial_value})",
-        f"fn get_{motor}_{data_name}(&mut self, id: u8) -> "
+        f"{fn} get_{motor}_{data_name}(&mut self, id: u8) -> "
         f"Result<u{size * 8}, Error<Serial>> {{",
-        "    let mut content : Vec<u8, 4> = Vec::new();",
-        f"    content.push({address[0]}).map_err(|_| Error::TooSmall)?;",
-        "    if PROTOCOL_VERSION == 2 {",
-        f"        content.push({address[1]}).map_err(|_| Error::TooSmall)?;",
-        "    }",
-        f"    content.push({size_t[0]}).map_err(|_| Error::TooSmall)?;",
-        "    if PROTOCOL_VERSION == 2 {",
-        f"        content.push({size_t[1]}).map_err(|_| Error::TooSmall)?;",
-        "    }",
-        "    self.send(id, Instruction::Read, content)?;",
-        "    if self.n_recv() == 2 { self.recv::<4>()?; }"
-        f"    let params = self.recv::<{size}>()?.params;",
-        f"    Ok(u{size * 8}::from_le_bytes(params.into_array()"
-        ".map_err(|_| Error::TooSmall)?))",
+        f"    Ok(u{size * 8}::from_le_bytes(self.read(id, {address}){aw}?))",
         "}",
     ]
     if access == "RW":
         lines += [
-            f"fn set_{motor}_{data_name}(&mut self, id: u8, params: u{size * 8}) -> "
+            f"{fn} set_{motor}_{data_name}(&mut self, id: u8, params: u{size * 8}) -> "
             f"Result<Option<StatusPacket<{size}>>, Error<Serial>> {{",
-            f"    let mut content : Vec<u8, {2 + size}> = Vec::new();",
-            f"    content.push({address[0]}).map_err(|_| Error::TooSmall)?;",
-            "    if PROTOCOL_VERSION == 2 {",
-            f"        content.push({address[1]}).map_err(|_| Error::TooSmall)?;",
-            "    }",
-            "    for byte in params.to_le_bytes() {",
-            "        content.push(byte).map_err(|_| Error::TooSmall)?;",
-            "    }",
-            "    self.send(id, Instruction::Write, content)?;",
-            f"    if self.n_recv() == 2 {{ self.recv::<{2 + size}>()?; }}"
-            f"    if self.n_recv() >= 1 {{ Ok(Some(self.recv::<{size}>()?)) }} "
-            "else { Ok(None) }}",
+            f"    self.write::<{2 + size}, {size}>"
+            f"(id, {address}, &params.to_le_bytes()){aw}",
+            "}",
         ]
     for line in lines:
         print(line, file=out)
 
 
-def main(motor: Path, motor_name: str):
+def emit(motor_name: str, rows):
     generated = f"generated/{motor_name}.rs"
-    with motor.open() as mdfile, open(generated, "w") as rsfile:
-        has_description = False
+    # elided registers are only reachable through the indexed getters and setters
+    table, families = expand(rows)
+    indexed = [(family, runs(table, family)) for family in sorted(families)]
+    with open(generated, "w") as rsfile:
         print(HEAD.replace("MOTOR", motor_name.upper()), file=rsfile)
+        for row in rows:
+            generate(*row, mini=None, maxi=None, motor=motor_name, out=rsfile)
+        for family, family_runs in indexed:
+            generate_indexed(family, family_runs, motor=motor_name, out=rsfile)
+        print(TAIL.replace("MOTOR", motor_name.upper()), file=rsfile)
+        print(ASYNC_HEAD.replace("MOTOR", motor_name.upper()), file=rsfile)
+        for row in rows:
+            generate(
+                *row,
+                mini=None,
+                maxi=None,
+                motor=motor_name,
+                out=rsfile,
+                asynchronous=True,
+            )
+        for family, family_runs in indexed:
+            generate_indexed(
+                family, family_runs, motor=motor_name, out=rsfile, asynchronous=True
+            )
+        print(ASYNC_TAIL.replace("MOTOR", motor_name.upper()), file=rsfile)
+        control_table(table, rsfile)
+    run(["rustfmt", "--edition", "2018", generated])
+    return model_number(rows)
+
+
+def emit_mod(models):
+    """mod.rs, with the models of known model numbers"""
+    with open("generated/mod.rs", "w") as mod:
+        for motor_name, number in models:
+            print(f"pub mod {motor_name};", file=mod)
+        print("", file=mod)
+        print("use crate::control_table::Model;", file=mod)
+        print("", file=mod)
+        print("/// Models with a documented model number", file=mod)
+        print("pub const MODELS: &[Model] = &[", file=mod)
+        for motor_name, number in models:
+            if number is not None:
+                lines = [
+                    "Model {",
+                    f'    name: "{motor_name}",',
+                    f"    model_number: {motor_name}::MODEL_NUMBER,",
+                    f"    control_table: {motor_name}::CONTROL_TABLE,",
+                    "},",
+                ]
+                for line in lines:
+                    print(line, file=mod)
+        print("];", file=mod)
+    run(["rustfmt", "--edition", "2018", "generated/mod.rs"])
+
+
+def main(motor: Path, motor_name: str):
+    rows = []
+    with motor.open() as mdfile:
+        has_description = False
         state, data = 0, False
         for line in mdfile:
             if "control-table-of" in line:
@@ -139,38 +383,28 @@ def main(motor: Path, motor_name: str):
                         item.strip() for item in line.split("|")[1:-1]
                     )
                     description = data_name
-                generate(
-                    address,
-                    size,
-                    data_name,
-                    description,
-                    access,
-                    initial_value,
-                    mini=None,
-                    maxi=None,
-                    motor=motor_name,
-                    out=rsfile,
+                rows.append(
+                    (address, size, data_name, description, access, initial_value)
                 )
-        print(TAIL.replace("MOTOR", motor_name.upper()), file=rsfile)
-    run(["rustfmt", generated])
+    return emit(motor_name, rows)
 
 
 if __name__ == "__main__":
-    with open("generated/mod.rs", "w") as mod:
-        for serie in Path("../emanual/docs/en/dxl/").iterdir():
-            if serie.is_dir() and serie.name != "p":
-                for motor in serie.iterdir():
-                    if motor.name not in [
-                        "2xc430-w250.md",
-                        "2xl430-w250.md",
-                        "x.md",
-                        "pro.md",
-                    ]:
-                        motor_name = (
-                            motor.stem.replace("_", "")
-                            .replace("-", "")
-                            .replace("+", "plus")
-                        )
-                        print(motor)
-                        main(motor, motor_name)
-                        print(f"pub mod {motor_name};", file=mod)
+    models = []
+    for serie in Path("../emanual/docs/en/dxl/").iterdir():
+        if serie.is_dir() and serie.name != "p":
+            for motor in serie.iterdir():
+                if motor.name not in [
+                    "2xc430-w250.md",
+                    "2xl430-w250.md",
+                    "x.md",
+                    "pro.md",
+                ]:
+                    motor_name = (
+                        motor.stem.replace("_", "")
+                        .replace("-", "")
+                        .replace("+", "plus")
+                    )
+                    print(motor)
+                    models.append((motor_name, main(motor, motor_name)))
+    emit_mod(sorted(models))
@@ -1,1192 +1,899 @@
-use crate::protocol::{Controller, Error, Instruction, Protocol, StatusPacket};
-use embedded_hal::{digital::v2::OutputPin, serial};
-use heapless::Vec;
+use crate::control_table::Register;
+use crate::protocol::{Controller, Error, Protocol, StatusPacket};
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+use embedded_io::{Read, ReadReady, Write};
 
 pub trait AX18A<Serial, const PROTOCOL_VERSION: u8>: Protocol<Serial, PROTOCOL_VERSION>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
 {
     /// Model Number (initial: 18)
     fn get_ax18a_model_number(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(0).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 0)?))
     }
     /// Firmware Version (initial: -)
     fn get_ax18a_firmware_version(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 2)?))
     }
     /// DYNAMIXEL ID (initial: 1)
     fn get_ax18a_id(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(3).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 3)?))
     }
     fn set_ax18a_id(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(3).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 3, &params.to_le_bytes())
     }
     /// Communication Speed (initial: 1)
     fn get_ax18a_baud_rate(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 4)?))
     }
     fn set_ax18a_baud_rate(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 4, &params.to_le_bytes())
     }
     /// Response Delay Time (initial: 250)
     fn get_ax18a_return_delay_time(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(5).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 5)?))
     }
     fn set_ax18a_return_delay_time(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(5).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 5, &params.to_le_bytes())
     }
     /// Clockwise Angle Limit (initial: 0)
     fn get_ax18a_cw_angle_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(6).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 6)?))
     }
     fn set_ax18a_cw_angle_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(6).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 6, &params.to_le_bytes())
     }
     /// Counter-Clockwise Angle Limit (initial: 1023)
     fn get_ax18a_ccw_angle_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(8).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 8)?))
     }
     fn set_ax18a_ccw_angle_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(8).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 8, &params.to_le_bytes())
     }
     /// Maximum Internal Temperature Limit (initial: 75)
     fn get_ax18a_temperature_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(11).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 11)?))
     }
     fn set_ax18a_temperature_limit(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(11).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 11, &params.to_le_bytes())
     }
     /// Minimum Input Voltage Limit (initial: 60)
     fn get_ax18a_min_voltage_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(12).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 12)?))
     }
     fn set_ax18a_min_voltage_limit(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(12).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 12, &params.to_le_bytes())
     }
     /// Maximum Input Voltage Limit (initial: 140)
     fn get_ax18a_max_voltage_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(13).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 13)?))
     }
     fn set_ax18a_max_voltage_limit(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(13).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 13, &params.to_le_bytes())
     }
     /// Maximun Torque (initial: 983)
     fn get_ax18a_max_torque(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(14).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 14)?))
     }
     fn set_ax18a_max_torque(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(14).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 14, &params.to_le_bytes())
     }
     /// Select Types of Status Return (initial: 2)
     fn get_ax18a_status_return_level(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(16).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        let level = u8::from_le_bytes(self.read(id, 16)?);
+        self.set_status_return_level(id, level.into());
+        Ok(level)
     }
     fn set_ax18a_status_return_level(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(16).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        let status = self.write::<3, 1>(id, 16, &params.to_le_bytes())?;
+        self.set_status_return_level(id, params.into());
+        Ok(status)
     }
     /// LED for Alarm (initial: 36)
     fn get_ax18a_alarm_led(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(17).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 17)?))
     }
     fn set_ax18a_alarm_led(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(17).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 17, &params.to_le_bytes())
     }
     /// Shutdown Error Information (initial: 36)
     fn get_ax18a_shutdown(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(18).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 18)?))
     }
     fn set_ax18a_shutdown(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(18).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 18, &params.to_le_bytes())
     }
     /// Motor Torque On/Off (initial: 0)
     fn get_ax18a_torque_enable(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(24).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 24)?))
     }
     fn set_ax18a_torque_enable(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(24).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 24, &params.to_le_bytes())
     }
     /// Status LED On/Off (initial: 0)
     fn get_ax18a_led(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(25).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 25)?))
     }
     fn set_ax18a_led(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(25).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 25, &params.to_le_bytes())
     }
     /// CW Compliance Margin (initial: 1)
     fn get_ax18a_cw_compliance_margin(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(26).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 26)?))
     }
     fn set_ax18a_cw_compliance_margin(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(26).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 26, &params.to_le_bytes())
     }
     /// CCW Compliance Margin (initial: 1)
     fn get_ax18a_ccw_compliance_margin(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(27).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 27)?))
     }
     fn set_ax18a_ccw_compliance_margin(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(27).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 27, &params.to_le_bytes())
     }
     /// CW Compliance Slope (initial: 32)
     fn get_ax18a_cw_compliance_slope(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(28).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 28)?))
     }
     fn set_ax18a_cw_compliance_slope(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(28).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 28, &params.to_le_bytes())
     }
     /// CCW Compliance Slope (initial: 32)
     fn get_ax18a_ccw_compliance_slope(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(29).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 29)?))
     }
     fn set_ax18a_ccw_compliance_slope(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(29).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 29, &params.to_le_bytes())
     }
     /// Target Position (initial: -)
     fn get_ax18a_goal_position(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(30).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 30)?))
     }
     fn set_ax18a_goal_position(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(30).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 30, &params.to_le_bytes())
     }
     /// Moving Speed (initial: -)
     fn get_ax18a_moving_speed(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(32).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 32)?))
     }
     fn set_ax18a_moving_speed(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(32).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 32, &params.to_le_bytes())
     }
     /// Torque Limit (initial: Max Torque)
     fn get_ax18a_torque_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(34).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 34)?))
     }
     fn set_ax18a_torque_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(34).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 34, &params.to_le_bytes())
     }
     /// Present Position (initial: -)
     fn get_ax18a_present_position(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(36).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 36)?))
     }
     /// Present Speed (initial: -)
     fn get_ax18a_present_speed(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(38).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 38)?))
     }
     /// Present Load (initial: -)
     fn get_ax18a_present_load(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(40).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 40)?))
     }
     /// Present Voltage (initial: -)
     fn get_ax18a_present_voltage(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(42).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 42)?))
     }
     /// Present Temperature (initial: -)
     fn get_ax18a_present_temperature(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(43).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 43)?))
     }
     /// If Instruction is registered (initial: 0)
     fn get_ax18a_registered(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(44).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 44)?))
     }
     /// Movement Status (initial: 0)
     fn get_ax18a_moving(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(46).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 46)?))
     }
     /// Locking EEPROM (initial: 0)
     fn get_ax18a_lock(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(47).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 47)?))
     }
     fn set_ax18a_lock(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(47).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 47, &params.to_le_bytes())
     }
     /// Minimum Current Threshold (initial: 32)
     fn get_ax18a_punch(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(48).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 48)?))
     }
     fn set_ax18a_punch(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(48).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 48, &params.to_le_bytes())
     }
 }
 
-impl<Serial, Direction> AX18A<Serial, 1> for Controller<Serial, Direction, 1>
+impl<Serial, Direction, Delay> AX18A<Serial, 1> for Controller<Serial, Direction, 1, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
     Direction: OutputPin,
+    Delay: DelayNs,
 {
 }
 
-impl<Serial, Direction> AX18A<Serial, 2> for Controller<Serial, Direction, 2>
+impl<Serial, Direction, Delay> AX18A<Serial, 2> for Controller<Serial, Direction, 2, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
     Direction: OutputPin,
+    Delay: DelayNs,
 {
 }
+
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait AX18AAsync<Serial, const PROTOCOL_VERSION: u8>:
+    crate::protocol_async::AsyncProtocol<Serial, PROTOCOL_VERSION>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+{
+    /// Model Number (initial: 18)
+    async fn get_ax18a_model_number(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 0).await?))
+    }
+    /// Firmware Version (initial: -)
+    async fn get_ax18a_firmware_version(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 2).await?))
+    }
+    /// DYNAMIXEL ID (initial: 1)
+    async fn get_ax18a_id(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 3).await?))
+    }
+    async fn set_ax18a_id(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 3, &params.to_le_bytes()).await
+    }
+    /// Communication Speed (initial: 1)
+    async fn get_ax18a_baud_rate(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 4).await?))
+    }
+    async fn set_ax18a_baud_rate(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 4, &params.to_le_bytes()).await
+    }
+    /// Response Delay Time (initial: 250)
+    async fn get_ax18a_return_delay_time(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 5).await?))
+    }
+    async fn set_ax18a_return_delay_time(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 5, &params.to_le_bytes()).await
+    }
+    /// Clockwise Angle Limit (initial: 0)
+    async fn get_ax18a_cw_angle_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 6).await?))
+    }
+    async fn set_ax18a_cw_angle_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 6, &params.to_le_bytes()).await
+    }
+    /// Counter-Clockwise Angle Limit (initial: 1023)
+    async fn get_ax18a_ccw_angle_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 8).await?))
+    }
+    async fn set_ax18a_ccw_angle_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 8, &params.to_le_bytes()).await
+    }
+    /// Maximum Internal Temperature Limit (initial: 75)
+    async fn get_ax18a_temperature_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 11).await?))
+    }
+    async fn set_ax18a_temperature_limit(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 11, &params.to_le_bytes()).await
+    }
+    /// Minimum Input Voltage Limit (initial: 60)
+    async fn get_ax18a_min_voltage_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 12).await?))
+    }
+    async fn set_ax18a_min_voltage_limit(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 12, &params.to_le_bytes()).await
+    }
+    /// Maximum Input Voltage Limit (initial: 140)
+    async fn get_ax18a_max_voltage_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 13).await?))
+    }
+    async fn set_ax18a_max_voltage_limit(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 13, &params.to_le_bytes()).await
+    }
+    /// Maximun Torque (initial: 983)
+    async fn get_ax18a_max_torque(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 14).await?))
+    }
+    async fn set_ax18a_max_torque(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 14, &params.to_le_bytes()).await
+    }
+    /// Select Types of Status Return (initial: 2)
+    async fn get_ax18a_status_return_level(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        let level = u8::from_le_bytes(self.read(id, 16).await?);
+        self.set_status_return_level(id, level.into());
+        Ok(level)
+    }
+    async fn set_ax18a_status_return_level(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        let status = self.write::<3, 1>(id, 16, &params.to_le_bytes()).await?;
+        self.set_status_return_level(id, params.into());
+        Ok(status)
+    }
+    /// LED for Alarm (initial: 36)
+    async fn get_ax18a_alarm_led(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 17).await?))
+    }
+    async fn set_ax18a_alarm_led(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 17, &params.to_le_bytes()).await
+    }
+    /// Shutdown Error Information (initial: 36)
+    async fn get_ax18a_shutdown(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 18).await?))
+    }
+    async fn set_ax18a_shutdown(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 18, &params.to_le_bytes()).await
+    }
+    /// Motor Torque On/Off (initial: 0)
+    async fn get_ax18a_torque_enable(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 24).await?))
+    }
+    async fn set_ax18a_torque_enable(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 24, &params.to_le_bytes()).await
+    }
+    /// Status LED On/Off (initial: 0)
+    async fn get_ax18a_led(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 25).await?))
+    }
+    async fn set_ax18a_led(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 25, &params.to_le_bytes()).await
+    }
+    /// CW Compliance Margin (initial: 1)
+    async fn get_ax18a_cw_compliance_margin(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 26).await?))
+    }
+    async fn set_ax18a_cw_compliance_margin(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 26, &params.to_le_bytes()).await
+    }
+    /// CCW Compliance Margin (initial: 1)
+    async fn get_ax18a_ccw_compliance_margin(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 27).await?))
+    }
+    async fn set_ax18a_ccw_compliance_margin(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 27, &params.to_le_bytes()).await
+    }
+    /// CW Compliance Slope (initial: 32)
+    async fn get_ax18a_cw_compliance_slope(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 28).await?))
+    }
+    async fn set_ax18a_cw_compliance_slope(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 28, &params.to_le_bytes()).await
+    }
+    /// CCW Compliance Slope (initial: 32)
+    async fn get_ax18a_ccw_compliance_slope(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 29).await?))
+    }
+    async fn set_ax18a_ccw_compliance_slope(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 29, &params.to_le_bytes()).await
+    }
+    /// Target Position (initial: -)
+    async fn get_ax18a_goal_position(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 30).await?))
+    }
+    async fn set_ax18a_goal_position(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 30, &params.to_le_bytes()).await
+    }
+    /// Moving Speed (initial: -)
+    async fn get_ax18a_moving_speed(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 32).await?))
+    }
+    async fn set_ax18a_moving_speed(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 32, &params.to_le_bytes()).await
+    }
+    /// Torque Limit (initial: Max Torque)
+    async fn get_ax18a_torque_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 34).await?))
+    }
+    async fn set_ax18a_torque_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 34, &params.to_le_bytes()).await
+    }
+    /// Present Position (initial: -)
+    async fn get_ax18a_present_position(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 36).await?))
+    }
+    /// Present Speed (initial: -)
+    async fn get_ax18a_present_speed(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 38).await?))
+    }
+    /// Present Load (initial: -)
+    async fn get_ax18a_present_load(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 40).await?))
+    }
+    /// Present Voltage (initial: -)
+    async fn get_ax18a_present_voltage(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 42).await?))
+    }
+    /// Present Temperature (initial: -)
+    async fn get_ax18a_present_temperature(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 43).await?))
+    }
+    /// If Instruction is registered (initial: 0)
+    async fn get_ax18a_registered(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 44).await?))
+    }
+    /// Movement Status (initial: 0)
+    async fn get_ax18a_moving(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 46).await?))
+    }
+    /// Locking EEPROM (initial: 0)
+    async fn get_ax18a_lock(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 47).await?))
+    }
+    async fn set_ax18a_lock(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 47, &params.to_le_bytes()).await
+    }
+    /// Minimum Current Threshold (initial: 32)
+    async fn get_ax18a_punch(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 48).await?))
+    }
+    async fn set_ax18a_punch(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 48, &params.to_le_bytes()).await
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Serial, Direction, Delay> AX18AAsync<Serial, 1> for Controller<Serial, Direction, 1, Delay>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+    Direction: OutputPin,
+    Delay: embedded_hal_async::delay::DelayNs,
+{
+}
+
+#[cfg(feature = "async")]
+impl<Serial, Direction, Delay> AX18AAsync<Serial, 2> for Controller<Serial, Direction, 2, Delay>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+    Direction: OutputPin,
+    Delay: embedded_hal_async::delay::DelayNs,
+{
+}
+
+pub const MODEL_NUMBER: u16 = 18;
+pub const CONTROL_TABLE: &[Register] = &[
+    Register {
+        name: "model_number",
+        address: 0,
+        size: 2,
+        writable: false,
+        description: "Model Number",
+        initial: "18",
+    },
+    Register {
+        name: "firmware_version",
+        address: 2,
+        size: 1,
+        writable: false,
+        description: "Firmware Version",
+        initial: "-",
+    },
+    Register {
+        name: "id",
+        address: 3,
+        size: 1,
+        writable: true,
+        description: "DYNAMIXEL ID",
+        initial: "1",
+    },
+    Register {
+        name: "baud_rate",
+        address: 4,
+        size: 1,
+        writable: true,
+        description: "Communication Speed",
+        initial: "1",
+    },
+    Register {
+        name: "return_delay_time",
+        address: 5,
+        size: 1,
+        writable: true,
+        description: "Response Delay Time",
+        initial: "250",
+    },
+    Register {
+        name: "cw_angle_limit",
+        address: 6,
+        size: 2,
+        writable: true,
+        description: "Clockwise Angle Limit",
+        initial: "0",
+    },
+    Register {
+        name: "ccw_angle_limit",
+        address: 8,
+        size: 2,
+        writable: true,
+        description: "Counter-Clockwise Angle Limit",
+        initial: "1023",
+    },
+    Register {
+        name: "temperature_limit",
+        address: 11,
+        size: 1,
+        writable: true,
+        description: "Maximum Internal Temperature Limit",
+        initial: "75",
+    },
+    Register {
+        name: "min_voltage_limit",
+        address: 12,
+        size: 1,
+        writable: true,
+        description: "Minimum Input Voltage Limit",
+        initial: "60",
+    },
+    Register {
+        name: "max_voltage_limit",
+        address: 13,
+        size: 1,
+        writable: true,
+        description: "Maximum Input Voltage Limit",
+        initial: "140",
+    },
+    Register {
+        name: "max_torque",
+        address: 14,
+        size: 2,
+        writable: true,
+        description: "Maximun Torque",
+        initial: "983",
+    },
+    Register {
+        name: "status_return_level",
+        address: 16,
+        size: 1,
+        writable: true,
+        description: "Select Types of Status Return",
+        initial: "2",
+    },
+    Register {
+        name: "alarm_led",
+        address: 17,
+        size: 1,
+        writable: true,
+        description: "LED for Alarm",
+        initial: "36",
+    },
+    Register {
+        name: "shutdown",
+        address: 18,
+        size: 1,
+        writable: true,
+        description: "Shutdown Error Information",
+        initial: "36",
+    },
+    Register {
+        name: "torque_enable",
+        address: 24,
+        size: 1,
+        writable: true,
+        description: "Motor Torque On/Off",
+        initial: "0",
+    },
+    Register {
+        name: "led",
+        address: 25,
+        size: 1,
+        writable: true,
+        description: "Status LED On/Off",
+        initial: "0",
+    },
+    Register {
+        name: "cw_compliance_margin",
+        address: 26,
+        size: 1,
+        writable: true,
+        description: "CW Compliance Margin",
+        initial: "1",
+    },
+    Register {
+        name: "ccw_compliance_margin",
+        address: 27,
+        size: 1,
+        writable: true,
+        description: "CCW Compliance Margin",
+        initial: "1",
+    },
+    Register {
+        name: "cw_compliance_slope",
+        address: 28,
+        size: 1,
+        writable: true,
+        description: "CW Compliance Slope",
+        initial: "32",
+    },
+    Register {
+        name: "ccw_compliance_slope",
+        address: 29,
+        size: 1,
+        writable: true,
+        description: "CCW Compliance Slope",
+        initial: "32",
+    },
+    Register {
+        name: "goal_position",
+        address: 30,
+        size: 2,
+        writable: true,
+        description: "Target Position",
+        initial: "-",
+    },
+    Register {
+        name: "moving_speed",
+        address: 32,
+        size: 2,
+        writable: true,
+        description: "Moving Speed",
+        initial: "-",
+    },
+    Register {
+        name: "torque_limit",
+        address: 34,
+        size: 2,
+        writable: true,
+        description: "Torque Limit",
+        initial: "Max Torque",
+    },
+    Register {
+        name: "present_position",
+        address: 36,
+        size: 2,
+        writable: false,
+        description: "Present Position",
+        initial: "-",
+    },
+    Register {
+        name: "present_speed",
+        address: 38,
+        size: 2,
+        writable: false,
+        description: "Present Speed",
+        initial: "-",
+    },
+    Register {
+        name: "present_load",
+        address: 40,
+        size: 2,
+        writable: false,
+        description: "Present Load",
+        initial: "-",
+    },
+    Register {
+        name: "present_voltage",
+        address: 42,
+        size: 1,
+        writable: false,
+        description: "Present Voltage",
+        initial: "-",
+    },
+    Register {
+        name: "present_temperature",
+        address: 43,
+        size: 1,
+        writable: false,
+        description: "Present Temperature",
+        initial: "-",
+    },
+    Register {
+        name: "registered",
+        address: 44,
+        size: 1,
+        writable: false,
+        description: "If Instruction is registered",
+        initial: "0",
+    },
+    Register {
+        name: "moving",
+        address: 46,
+        size: 1,
+        writable: false,
+        description: "Movement Status",
+        initial: "0",
+    },
+    Register {
+        name: "lock",
+        address: 47,
+        size: 1,
+        writable: true,
+        description: "Locking EEPROM",
+        initial: "0",
+    },
+    Register {
+        name: "punch",
+        address: 48,
+        size: 2,
+        writable: true,
+        description: "Minimum Current Threshold",
+        initial: "32",
+    },
+];
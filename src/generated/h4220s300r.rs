@@ -1,2001 +1,5594 @@
-use crate::protocol::{Controller, Error, Instruction, Protocol, StatusPacket};
-use embedded_hal::{digital::v2::OutputPin, serial};
-use heapless::Vec;
+use crate::control_table::Register;
+use crate::protocol::{Controller, Error, Protocol, StatusPacket};
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+use embedded_io::{Read, ReadReady, Write};
 
 pub trait H4220S300R<Serial, const PROTOCOL_VERSION: u8>:
     Protocol<Serial, PROTOCOL_VERSION>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
 {
     /// [Model Number](#model-number) (initial: 51,200)
     fn get_h4220s300r_model_number(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(0).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 0)?))
     }
     /// [Model Information](#model-information) (initial: -)
     fn get_h4220s300r_model_information(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 2)?))
     }
     /// [Firmware Version](#firmware-version) (initial: -)
     fn get_h4220s300r_firmware_version(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(6).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 6)?))
     }
     /// [ID](#id) (initial: 1)
     fn get_h4220s300r_id(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(7).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 7)?))
     }
     fn set_h4220s300r_id(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(7).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 7, &params.to_le_bytes())
     }
     /// [Baud Rate](#baud-rate) (initial: 1)
     fn get_h4220s300r_baud_rate(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(8).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 8)?))
     }
     fn set_h4220s300r_baud_rate(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(8).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 8, &params.to_le_bytes())
     }
     /// [Return Delay Time](#return-delay-time) (initial: 250)
     fn get_h4220s300r_return_delay_time(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(9).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 9)?))
     }
     fn set_h4220s300r_return_delay_time(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(9).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 9, &params.to_le_bytes())
     }
     /// [Operating Mode](#operating-mode) (initial: 3)
     fn get_h4220s300r_operating_mode(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(11).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 11)?))
     }
     fn set_h4220s300r_operating_mode(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(11).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 11, &params.to_le_bytes())
     }
     /// [Homing Offset](#homing-offset) (initial: 0)
     fn get_h4220s300r_homing_offset(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(13).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 13)?))
     }
     fn set_h4220s300r_homing_offset(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(13).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 13, &params.to_le_bytes())
     }
     /// [Moving Threshold](#moving-threshold) (initial: 50)
     fn get_h4220s300r_moving_threshold(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(17).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 17)?))
     }
     fn set_h4220s300r_moving_threshold(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(17).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 17, &params.to_le_bytes())
     }
     /// [Temperature Limit](#temperature-limit) (initial: 80)
     fn get_h4220s300r_temperature_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(21).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 21)?))
     }
     fn set_h4220s300r_temperature_limit(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(21).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 21, &params.to_le_bytes())
     }
     /// [Max Voltage Limit](#max-voltage-limit) (initial: 400)
     fn get_h4220s300r_max_voltage_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(22).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 22)?))
     }
     fn set_h4220s300r_max_voltage_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(22).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 22, &params.to_le_bytes())
     }
     /// [Min Voltage Limit](#min-voltage-limit) (initial: 150)
     fn get_h4220s300r_min_voltage_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(24).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 24)?))
     }
     fn set_h4220s300r_min_voltage_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(24).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 24, &params.to_le_bytes())
     }
     /// [Acceleration Limit](#acceleration-limit) (initial: 255)
     fn get_h4220s300r_acceleration_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(26).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 26)?))
     }
     fn set_h4220s300r_acceleration_limit(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(26).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 26, &params.to_le_bytes())
     }
     /// [Torque Limit](#torque-limit) (initial: 465)
     fn get_h4220s300r_torque_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(30).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 30)?))
     }
     fn set_h4220s300r_torque_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(30).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 30, &params.to_le_bytes())
     }
     /// [Velocity Limit](#velocity-limit) (initial: 10,300)
     fn get_h4220s300r_velocity_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(32).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 32)?))
     }
     fn set_h4220s300r_velocity_limit(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(32).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 32, &params.to_le_bytes())
     }
     /// [Max Position Limit](#max-position-limit) (initial: 151,875)
     fn get_h4220s300r_max_position_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(36).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 36)?))
     }
     fn set_h4220s300r_max_position_limit(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(36).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 36, &params.to_le_bytes())
     }
     /// [Min Position Limit](#min-position-limit) (initial: -151,875)
     fn get_h4220s300r_min_position_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(40).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 40)?))
     }
     fn set_h4220s300r_min_position_limit(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(40).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 40, &params.to_le_bytes())
     }
     /// [External Port Mode 1](#external-port-mode) (initial: 0)
     fn get_h4220s300r_external_port_mode_1(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(44).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 44)?))
     }
     fn set_h4220s300r_external_port_mode_1(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(44).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 44, &params.to_le_bytes())
     }
     /// [External Port Mode 2](#external-port-mode) (initial: 0)
     fn get_h4220s300r_external_port_mode_2(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(45).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 45)?))
     }
     fn set_h4220s300r_external_port_mode_2(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(45).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 45, &params.to_le_bytes())
     }
     /// [External Port Mode 3](#external-port-mode) (initial: 0)
     fn get_h4220s300r_external_port_mode_3(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(46).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 46)?))
     }
     fn set_h4220s300r_external_port_mode_3(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(46).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 46, &params.to_le_bytes())
     }
     /// [External Port Mode 4](#external-port-mode) (initial: 0)
     fn get_h4220s300r_external_port_mode_4(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(47).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 47)?))
     }
     fn set_h4220s300r_external_port_mode_4(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(47).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 47, &params.to_le_bytes())
     }
     /// [Shutdown](#shutdown) (initial: 58)
     fn get_h4220s300r_shutdown(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(48).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 48)?))
     }
     fn set_h4220s300r_shutdown(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(48).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 48, &params.to_le_bytes())
     }
     /// [Indirect Address 1](#indirect-address) (initial: 634)
     fn get_h4220s300r_indirect_address_1(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(49).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 49)?))
     }
     fn set_h4220s300r_indirect_address_1(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(49).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 49, &params.to_le_bytes())
     }
     /// [Indirect Address 2](#indirect-address) (initial: 635)
     fn get_h4220s300r_indirect_address_2(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(51).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 51)?))
     }
     fn set_h4220s300r_indirect_address_2(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(51).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 51, &params.to_le_bytes())
     }
     /// [Indirect Address 3](#indirect-address) (initial: 636)
     fn get_h4220s300r_indirect_address_3(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(53).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 53)?))
     }
     fn set_h4220s300r_indirect_address_3(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(53).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 53, &params.to_le_bytes())
     }
     /// [Indirect Address 256](#indirect-address) (initial: 889)
     fn get_h4220s300r_indirect_address_256(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(47).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 559)?))
     }
     fn set_h4220s300r_indirect_address_256(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(47).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 559, &params.to_le_bytes())
     }
     /// [Torque Enable](#torque-enable) (initial: 0)
     fn get_h4220s300r_torque_enable(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(50).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 562)?))
     }
     fn set_h4220s300r_torque_enable(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(50).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 562, &params.to_le_bytes())
     }
     /// [LED Red](#led) (initial: 0)
     fn get_h4220s300r_led_red(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(51).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 563)?))
     }
     fn set_h4220s300r_led_red(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(51).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 563, &params.to_le_bytes())
     }
     /// [LED Green](#led) (initial: 0)
     fn get_h4220s300r_led_green(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(52).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 564)?))
     }
     fn set_h4220s300r_led_green(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(52).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 564, &params.to_le_bytes())
     }
     /// [LED Blue](#led) (initial: 0)
     fn get_h4220s300r_led_blue(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(53).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 565)?))
     }
     fn set_h4220s300r_led_blue(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(53).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 565, &params.to_le_bytes())
     }
     /// [Velocity I Gain](#velocity-i-gain) (initial: 40)
     fn get_h4220s300r_velocity_i_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(74).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 586)?))
     }
     fn set_h4220s300r_velocity_i_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(74).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 586, &params.to_le_bytes())
     }
     /// [Velocity P Gain](#velocity-p-gain) (initial: 440)
     fn get_h4220s300r_velocity_p_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(76).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 588)?))
     }
     fn set_h4220s300r_velocity_p_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(76).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 588, &params.to_le_bytes())
     }
     /// [Position P Gain](#position-p-gain) (initial: 32)
     fn get_h4220s300r_position_p_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(82).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 594)?))
     }
     fn set_h4220s300r_position_p_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(82).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 594, &params.to_le_bytes())
     }
     /// [Goal Position](#goal-position) (initial: -)
     fn get_h4220s300r_goal_position(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(84).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 596)?))
     }
     fn set_h4220s300r_goal_position(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(84).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 596, &params.to_le_bytes())
     }
     /// [Goal Velocity](#goal-velocity) (initial: 0)
     fn get_h4220s300r_goal_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(88).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 600)?))
     }
     fn set_h4220s300r_goal_velocity(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(88).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 600, &params.to_le_bytes())
     }
     /// [Goal Torque](#goal-torque) (initial: 0)
     fn get_h4220s300r_goal_torque(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(92).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 604)?))
     }
     fn set_h4220s300r_goal_torque(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(92).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 604, &params.to_le_bytes())
     }
     /// [Goal Acceleration](#goal-acceleration) (initial: 0)
     fn get_h4220s300r_goal_acceleration(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(94).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 606)?))
     }
     fn set_h4220s300r_goal_acceleration(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(94).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 606, &params.to_le_bytes())
     }
     /// [Moving](#moving) (initial: -)
     fn get_h4220s300r_moving(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(98).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 610)?))
     }
     /// [Present Position](#present-position) (initial: -)
     fn get_h4220s300r_present_position(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(99).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 611)?))
     }
     /// [Present Velocity](#present-velocity) (initial: -)
     fn get_h4220s300r_present_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(103).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 615)?))
     }
     /// [Present Current](#present-current) (initial: -)
     fn get_h4220s300r_present_current(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(109).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 621)?))
     }
     /// [Present Input Voltage](#present-input-voltage) (initial: -)
     fn get_h4220s300r_present_input_voltage(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(111).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 623)?))
     }
     /// [Present Temperature](#present-temperature) (initial: -)
     fn get_h4220s300r_present_temperature(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(113).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 625)?))
     }
     /// [External Port Data 1](#external-port-data) (initial: 0)
     fn get_h4220s300r_external_port_data_1(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(114).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 626)?))
     }
     /// [External Port Data 2](#external-port-data) (initial: 0)
     fn get_h4220s300r_external_port_data_2(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(116).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 628)?))
     }
     /// [External Port Data 3](#external-port-data) (initial: 0)
     fn get_h4220s300r_external_port_data_3(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(118).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 630)?))
     }
     /// [External Port Data 4](#external-port-data) (initial: 0)
     fn get_h4220s300r_external_port_data_4(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(120).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 632)?))
     }
     /// [Indirect Data 1](#indirect-data) (initial: 0)
     fn get_h4220s300r_indirect_data_1(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(122).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 634)?))
     }
     fn set_h4220s300r_indirect_data_1(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(122).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 634, &params.to_le_bytes())
     }
     /// [Indirect Data 2](#indirect-data) (initial: 0)
     fn get_h4220s300r_indirect_data_2(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(123).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 635)?))
     }
     fn set_h4220s300r_indirect_data_2(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(123).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 635, &params.to_le_bytes())
     }
     /// [Indirect Data 3](#indirect-data) (initial: 0)
     fn get_h4220s300r_indirect_data_3(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(124).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 636)?))
     }
     fn set_h4220s300r_indirect_data_3(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(124).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 636, &params.to_le_bytes())
     }
     /// [Indirect Data 256](#indirect-data) (initial: 0)
     fn get_h4220s300r_indirect_data_256(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(121).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(3).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 889)?))
     }
     fn set_h4220s300r_indirect_data_256(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(121).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(3).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 889, &params.to_le_bytes())
     }
     /// [Registered Instruction](#registered-instruction) (initial: 0)
     fn get_h4220s300r_registered_instruction(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(122).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(3).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 890)?))
     }
     /// [Status Return Level](#status-return-level) (initial: 2)
     fn get_h4220s300r_status_return_level(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(123).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(3).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        let level = u8::from_le_bytes(self.read(id, 891)?);
+        self.set_status_return_level(id, level.into());
+        Ok(level)
     }
     fn set_h4220s300r_status_return_level(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(123).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(3).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        let status = self.write::<3, 1>(id, 891, &params.to_le_bytes())?;
+        self.set_status_return_level(id, params.into());
+        Ok(status)
     }
     /// [Hardware Error Status](#hardware-error-status) (initial: 0)
     fn get_h4220s300r_hardware_error_status(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(124).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(3).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 892)?))
+    }
+    /// [Indirect Address N](#indirect-address), for N in 1..=256
+    fn get_h4220s300r_indirect_address(&mut self, id: u8, n: u16) -> Result<u16, Error<Serial>> {
+        let address = match n {
+            1..=256 => 49 + 2 * (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        Ok(u16::from_le_bytes(self.read(id, address)?))
+    }
+    fn set_h4220s300r_indirect_address(
+        &mut self,
+        id: u8,
+        n: u16,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        let address = match n {
+            1..=256 => 49 + 2 * (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        self.write::<4, 2>(id, address, &params.to_le_bytes())
+    }
+    /// [Indirect Data N](#indirect-data), for N in 1..=256
+    fn get_h4220s300r_indirect_data(&mut self, id: u8, n: u16) -> Result<u8, Error<Serial>> {
+        let address = match n {
+            1..=256 => 634 + (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        Ok(u8::from_le_bytes(self.read(id, address)?))
+    }
+    fn set_h4220s300r_indirect_data(
+        &mut self,
+        id: u8,
+        n: u16,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        let address = match n {
+            1..=256 => 634 + (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        self.write::<3, 1>(id, address, &params.to_le_bytes())
+    }
+}
+
+impl<Serial, Direction, Delay> H4220S300R<Serial, 1> for Controller<Serial, Direction, 1, Delay>
+where
+    Serial: Read + ReadReady + Write,
+    Direction: OutputPin,
+    Delay: DelayNs,
+{
+}
+
+impl<Serial, Direction, Delay> H4220S300R<Serial, 2> for Controller<Serial, Direction, 2, Delay>
+where
+    Serial: Read + ReadReady + Write,
+    Direction: OutputPin,
+    Delay: DelayNs,
+{
+}
+
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait H4220S300RAsync<Serial, const PROTOCOL_VERSION: u8>:
+    crate::protocol_async::AsyncProtocol<Serial, PROTOCOL_VERSION>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+{
+    /// [Model Number](#model-number) (initial: 51,200)
+    async fn get_h4220s300r_model_number(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 0).await?))
+    }
+    /// [Model Information](#model-information) (initial: -)
+    async fn get_h4220s300r_model_information(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 2).await?))
+    }
+    /// [Firmware Version](#firmware-version) (initial: -)
+    async fn get_h4220s300r_firmware_version(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 6).await?))
+    }
+    /// [ID](#id) (initial: 1)
+    async fn get_h4220s300r_id(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 7).await?))
+    }
+    async fn set_h4220s300r_id(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 7, &params.to_le_bytes()).await
+    }
+    /// [Baud Rate](#baud-rate) (initial: 1)
+    async fn get_h4220s300r_baud_rate(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 8).await?))
+    }
+    async fn set_h4220s300r_baud_rate(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 8, &params.to_le_bytes()).await
+    }
+    /// [Return Delay Time](#return-delay-time) (initial: 250)
+    async fn get_h4220s300r_return_delay_time(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 9).await?))
+    }
+    async fn set_h4220s300r_return_delay_time(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 9, &params.to_le_bytes()).await
+    }
+    /// [Operating Mode](#operating-mode) (initial: 3)
+    async fn get_h4220s300r_operating_mode(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 11).await?))
+    }
+    async fn set_h4220s300r_operating_mode(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 11, &params.to_le_bytes()).await
+    }
+    /// [Homing Offset](#homing-offset) (initial: 0)
+    async fn get_h4220s300r_homing_offset(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 13).await?))
+    }
+    async fn set_h4220s300r_homing_offset(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 13, &params.to_le_bytes()).await
+    }
+    /// [Moving Threshold](#moving-threshold) (initial: 50)
+    async fn get_h4220s300r_moving_threshold(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 17).await?))
+    }
+    async fn set_h4220s300r_moving_threshold(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 17, &params.to_le_bytes()).await
+    }
+    /// [Temperature Limit](#temperature-limit) (initial: 80)
+    async fn get_h4220s300r_temperature_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 21).await?))
+    }
+    async fn set_h4220s300r_temperature_limit(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 21, &params.to_le_bytes()).await
+    }
+    /// [Max Voltage Limit](#max-voltage-limit) (initial: 400)
+    async fn get_h4220s300r_max_voltage_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 22).await?))
+    }
+    async fn set_h4220s300r_max_voltage_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 22, &params.to_le_bytes()).await
+    }
+    /// [Min Voltage Limit](#min-voltage-limit) (initial: 150)
+    async fn get_h4220s300r_min_voltage_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 24).await?))
+    }
+    async fn set_h4220s300r_min_voltage_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 24, &params.to_le_bytes()).await
+    }
+    /// [Acceleration Limit](#acceleration-limit) (initial: 255)
+    async fn get_h4220s300r_acceleration_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 26).await?))
+    }
+    async fn set_h4220s300r_acceleration_limit(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 26, &params.to_le_bytes()).await
+    }
+    /// [Torque Limit](#torque-limit) (initial: 465)
+    async fn get_h4220s300r_torque_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 30).await?))
+    }
+    async fn set_h4220s300r_torque_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 30, &params.to_le_bytes()).await
+    }
+    /// [Velocity Limit](#velocity-limit) (initial: 10,300)
+    async fn get_h4220s300r_velocity_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 32).await?))
+    }
+    async fn set_h4220s300r_velocity_limit(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 32, &params.to_le_bytes()).await
+    }
+    /// [Max Position Limit](#max-position-limit) (initial: 151,875)
+    async fn get_h4220s300r_max_position_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 36).await?))
+    }
+    async fn set_h4220s300r_max_position_limit(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 36, &params.to_le_bytes()).await
+    }
+    /// [Min Position Limit](#min-position-limit) (initial: -151,875)
+    async fn get_h4220s300r_min_position_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 40).await?))
+    }
+    async fn set_h4220s300r_min_position_limit(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 40, &params.to_le_bytes()).await
+    }
+    /// [External Port Mode 1](#external-port-mode) (initial: 0)
+    async fn get_h4220s300r_external_port_mode_1(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 44).await?))
+    }
+    async fn set_h4220s300r_external_port_mode_1(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 44, &params.to_le_bytes()).await
+    }
+    /// [External Port Mode 2](#external-port-mode) (initial: 0)
+    async fn get_h4220s300r_external_port_mode_2(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 45).await?))
+    }
+    async fn set_h4220s300r_external_port_mode_2(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 45, &params.to_le_bytes()).await
+    }
+    /// [External Port Mode 3](#external-port-mode) (initial: 0)
+    async fn get_h4220s300r_external_port_mode_3(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 46).await?))
+    }
+    async fn set_h4220s300r_external_port_mode_3(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 46, &params.to_le_bytes()).await
+    }
+    /// [External Port Mode 4](#external-port-mode) (initial: 0)
+    async fn get_h4220s300r_external_port_mode_4(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 47).await?))
+    }
+    async fn set_h4220s300r_external_port_mode_4(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 47, &params.to_le_bytes()).await
+    }
+    /// [Shutdown](#shutdown) (initial: 58)
+    async fn get_h4220s300r_shutdown(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 48).await?))
+    }
+    async fn set_h4220s300r_shutdown(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 48, &params.to_le_bytes()).await
+    }
+    /// [Indirect Address 1](#indirect-address) (initial: 634)
+    async fn get_h4220s300r_indirect_address_1(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 49).await?))
+    }
+    async fn set_h4220s300r_indirect_address_1(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 49, &params.to_le_bytes()).await
+    }
+    /// [Indirect Address 2](#indirect-address) (initial: 635)
+    async fn get_h4220s300r_indirect_address_2(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 51).await?))
+    }
+    async fn set_h4220s300r_indirect_address_2(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 51, &params.to_le_bytes()).await
+    }
+    /// [Indirect Address 3](#indirect-address) (initial: 636)
+    async fn get_h4220s300r_indirect_address_3(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 53).await?))
+    }
+    async fn set_h4220s300r_indirect_address_3(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 53, &params.to_le_bytes()).await
+    }
+    /// [Indirect Address 256](#indirect-address) (initial: 889)
+    async fn get_h4220s300r_indirect_address_256(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 559).await?))
+    }
+    async fn set_h4220s300r_indirect_address_256(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 559, &params.to_le_bytes()).await
+    }
+    /// [Torque Enable](#torque-enable) (initial: 0)
+    async fn get_h4220s300r_torque_enable(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 562).await?))
+    }
+    async fn set_h4220s300r_torque_enable(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 562, &params.to_le_bytes()).await
+    }
+    /// [LED Red](#led) (initial: 0)
+    async fn get_h4220s300r_led_red(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 563).await?))
+    }
+    async fn set_h4220s300r_led_red(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 563, &params.to_le_bytes()).await
+    }
+    /// [LED Green](#led) (initial: 0)
+    async fn get_h4220s300r_led_green(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 564).await?))
+    }
+    async fn set_h4220s300r_led_green(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 564, &params.to_le_bytes()).await
+    }
+    /// [LED Blue](#led) (initial: 0)
+    async fn get_h4220s300r_led_blue(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 565).await?))
+    }
+    async fn set_h4220s300r_led_blue(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 565, &params.to_le_bytes()).await
+    }
+    /// [Velocity I Gain](#velocity-i-gain) (initial: 40)
+    async fn get_h4220s300r_velocity_i_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 586).await?))
+    }
+    async fn set_h4220s300r_velocity_i_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 586, &params.to_le_bytes()).await
+    }
+    /// [Velocity P Gain](#velocity-p-gain) (initial: 440)
+    async fn get_h4220s300r_velocity_p_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 588).await?))
+    }
+    async fn set_h4220s300r_velocity_p_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 588, &params.to_le_bytes()).await
+    }
+    /// [Position P Gain](#position-p-gain) (initial: 32)
+    async fn get_h4220s300r_position_p_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 594).await?))
+    }
+    async fn set_h4220s300r_position_p_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 594, &params.to_le_bytes()).await
+    }
+    /// [Goal Position](#goal-position) (initial: -)
+    async fn get_h4220s300r_goal_position(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 596).await?))
+    }
+    async fn set_h4220s300r_goal_position(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 596, &params.to_le_bytes()).await
+    }
+    /// [Goal Velocity](#goal-velocity) (initial: 0)
+    async fn get_h4220s300r_goal_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 600).await?))
+    }
+    async fn set_h4220s300r_goal_velocity(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 600, &params.to_le_bytes()).await
+    }
+    /// [Goal Torque](#goal-torque) (initial: 0)
+    async fn get_h4220s300r_goal_torque(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 604).await?))
+    }
+    async fn set_h4220s300r_goal_torque(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 604, &params.to_le_bytes()).await
+    }
+    /// [Goal Acceleration](#goal-acceleration) (initial: 0)
+    async fn get_h4220s300r_goal_acceleration(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 606).await?))
+    }
+    async fn set_h4220s300r_goal_acceleration(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 606, &params.to_le_bytes()).await
+    }
+    /// [Moving](#moving) (initial: -)
+    async fn get_h4220s300r_moving(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 610).await?))
+    }
+    /// [Present Position](#present-position) (initial: -)
+    async fn get_h4220s300r_present_position(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 611).await?))
+    }
+    /// [Present Velocity](#present-velocity) (initial: -)
+    async fn get_h4220s300r_present_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 615).await?))
+    }
+    /// [Present Current](#present-current) (initial: -)
+    async fn get_h4220s300r_present_current(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 621).await?))
+    }
+    /// [Present Input Voltage](#present-input-voltage) (initial: -)
+    async fn get_h4220s300r_present_input_voltage(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 623).await?))
+    }
+    /// [Present Temperature](#present-temperature) (initial: -)
+    async fn get_h4220s300r_present_temperature(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 625).await?))
+    }
+    /// [External Port Data 1](#external-port-data) (initial: 0)
+    async fn get_h4220s300r_external_port_data_1(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 626).await?))
+    }
+    /// [External Port Data 2](#external-port-data) (initial: 0)
+    async fn get_h4220s300r_external_port_data_2(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 628).await?))
+    }
+    /// [External Port Data 3](#external-port-data) (initial: 0)
+    async fn get_h4220s300r_external_port_data_3(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 630).await?))
+    }
+    /// [External Port Data 4](#external-port-data) (initial: 0)
+    async fn get_h4220s300r_external_port_data_4(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 632).await?))
+    }
+    /// [Indirect Data 1](#indirect-data) (initial: 0)
+    async fn get_h4220s300r_indirect_data_1(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 634).await?))
+    }
+    async fn set_h4220s300r_indirect_data_1(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 634, &params.to_le_bytes()).await
+    }
+    /// [Indirect Data 2](#indirect-data) (initial: 0)
+    async fn get_h4220s300r_indirect_data_2(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 635).await?))
+    }
+    async fn set_h4220s300r_indirect_data_2(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 635, &params.to_le_bytes()).await
+    }
+    /// [Indirect Data 3](#indirect-data) (initial: 0)
+    async fn get_h4220s300r_indirect_data_3(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 636).await?))
+    }
+    async fn set_h4220s300r_indirect_data_3(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 636, &params.to_le_bytes()).await
+    }
+    /// [Indirect Data 256](#indirect-data) (initial: 0)
+    async fn get_h4220s300r_indirect_data_256(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 889).await?))
+    }
+    async fn set_h4220s300r_indirect_data_256(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 889, &params.to_le_bytes()).await
+    }
+    /// [Registered Instruction](#registered-instruction) (initial: 0)
+    async fn get_h4220s300r_registered_instruction(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 890).await?))
+    }
+    /// [Status Return Level](#status-return-level) (initial: 2)
+    async fn get_h4220s300r_status_return_level(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        let level = u8::from_le_bytes(self.read(id, 891).await?);
+        self.set_status_return_level(id, level.into());
+        Ok(level)
+    }
+    async fn set_h4220s300r_status_return_level(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        let status = self.write::<3, 1>(id, 891, &params.to_le_bytes()).await?;
+        self.set_status_return_level(id, params.into());
+        Ok(status)
+    }
+    /// [Hardware Error Status](#hardware-error-status) (initial: 0)
+    async fn get_h4220s300r_hardware_error_status(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 892).await?))
+    }
+    /// [Indirect Address N](#indirect-address), for N in 1..=256
+    async fn get_h4220s300r_indirect_address(
+        &mut self,
+        id: u8,
+        n: u16,
+    ) -> Result<u16, Error<Serial>> {
+        let address = match n {
+            1..=256 => 49 + 2 * (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        Ok(u16::from_le_bytes(self.read(id, address).await?))
+    }
+    async fn set_h4220s300r_indirect_address(
+        &mut self,
+        id: u8,
+        n: u16,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        let address = match n {
+            1..=256 => 49 + 2 * (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        self.write::<4, 2>(id, address, &params.to_le_bytes()).await
+    }
+    /// [Indirect Data N](#indirect-data), for N in 1..=256
+    async fn get_h4220s300r_indirect_data(&mut self, id: u8, n: u16) -> Result<u8, Error<Serial>> {
+        let address = match n {
+            1..=256 => 634 + (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        Ok(u8::from_le_bytes(self.read(id, address).await?))
+    }
+    async fn set_h4220s300r_indirect_data(
+        &mut self,
+        id: u8,
+        n: u16,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        let address = match n {
+            1..=256 => 634 + (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        self.write::<3, 1>(id, address, &params.to_le_bytes()).await
     }
 }
 
-impl<Serial, Direction> H4220S300R<Serial, 1> for Controller<Serial, Direction, 1>
+#[cfg(feature = "async")]
+impl<Serial, Direction, Delay> H4220S300RAsync<Serial, 1>
+    for Controller<Serial, Direction, 1, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
     Direction: OutputPin,
+    Delay: embedded_hal_async::delay::DelayNs,
 {
 }
 
-impl<Serial, Direction> H4220S300R<Serial, 2> for Controller<Serial, Direction, 2>
+#[cfg(feature = "async")]
+impl<Serial, Direction, Delay> H4220S300RAsync<Serial, 2>
+    for Controller<Serial, Direction, 2, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
     Direction: OutputPin,
+    Delay: embedded_hal_async::delay::DelayNs,
 {
 }
+
+pub const MODEL_NUMBER: u16 = 51200;
+pub const CONTROL_TABLE: &[Register] = &[
+    Register {
+        name: "model_number",
+        address: 0,
+        size: 2,
+        writable: false,
+        description: "[Model Number](#model-number)",
+        initial: "51,200",
+    },
+    Register {
+        name: "model_information",
+        address: 2,
+        size: 4,
+        writable: false,
+        description: "[Model Information](#model-information)",
+        initial: "-",
+    },
+    Register {
+        name: "firmware_version",
+        address: 6,
+        size: 1,
+        writable: false,
+        description: "[Firmware Version](#firmware-version)",
+        initial: "-",
+    },
+    Register {
+        name: "id",
+        address: 7,
+        size: 1,
+        writable: true,
+        description: "[ID](#id)",
+        initial: "1",
+    },
+    Register {
+        name: "baud_rate",
+        address: 8,
+        size: 1,
+        writable: true,
+        description: "[Baud Rate](#baud-rate)",
+        initial: "1",
+    },
+    Register {
+        name: "return_delay_time",
+        address: 9,
+        size: 1,
+        writable: true,
+        description: "[Return Delay Time](#return-delay-time)",
+        initial: "250",
+    },
+    Register {
+        name: "operating_mode",
+        address: 11,
+        size: 1,
+        writable: true,
+        description: "[Operating Mode](#operating-mode)",
+        initial: "3",
+    },
+    Register {
+        name: "homing_offset",
+        address: 13,
+        size: 4,
+        writable: true,
+        description: "[Homing Offset](#homing-offset)",
+        initial: "0",
+    },
+    Register {
+        name: "moving_threshold",
+        address: 17,
+        size: 4,
+        writable: true,
+        description: "[Moving Threshold](#moving-threshold)",
+        initial: "50",
+    },
+    Register {
+        name: "temperature_limit",
+        address: 21,
+        size: 1,
+        writable: true,
+        description: "[Temperature Limit](#temperature-limit)",
+        initial: "80",
+    },
+    Register {
+        name: "max_voltage_limit",
+        address: 22,
+        size: 2,
+        writable: true,
+        description: "[Max Voltage Limit](#max-voltage-limit)",
+        initial: "400",
+    },
+    Register {
+        name: "min_voltage_limit",
+        address: 24,
+        size: 2,
+        writable: true,
+        description: "[Min Voltage Limit](#min-voltage-limit)",
+        initial: "150",
+    },
+    Register {
+        name: "acceleration_limit",
+        address: 26,
+        size: 4,
+        writable: true,
+        description: "[Acceleration Limit](#acceleration-limit)",
+        initial: "255",
+    },
+    Register {
+        name: "torque_limit",
+        address: 30,
+        size: 2,
+        writable: true,
+        description: "[Torque Limit](#torque-limit)",
+        initial: "465",
+    },
+    Register {
+        name: "velocity_limit",
+        address: 32,
+        size: 4,
+        writable: true,
+        description: "[Velocity Limit](#velocity-limit)",
+        initial: "10,300",
+    },
+    Register {
+        name: "max_position_limit",
+        address: 36,
+        size: 4,
+        writable: true,
+        description: "[Max Position Limit](#max-position-limit)",
+        initial: "151,875",
+    },
+    Register {
+        name: "min_position_limit",
+        address: 40,
+        size: 4,
+        writable: true,
+        description: "[Min Position Limit](#min-position-limit)",
+        initial: "-151,875",
+    },
+    Register {
+        name: "external_port_mode_1",
+        address: 44,
+        size: 1,
+        writable: true,
+        description: "[External Port Mode 1](#external-port-mode)",
+        initial: "0",
+    },
+    Register {
+        name: "external_port_mode_2",
+        address: 45,
+        size: 1,
+        writable: true,
+        description: "[External Port Mode 2](#external-port-mode)",
+        initial: "0",
+    },
+    Register {
+        name: "external_port_mode_3",
+        address: 46,
+        size: 1,
+        writable: true,
+        description: "[External Port Mode 3](#external-port-mode)",
+        initial: "0",
+    },
+    Register {
+        name: "external_port_mode_4",
+        address: 47,
+        size: 1,
+        writable: true,
+        description: "[External Port Mode 4](#external-port-mode)",
+        initial: "0",
+    },
+    Register {
+        name: "shutdown",
+        address: 48,
+        size: 1,
+        writable: true,
+        description: "[Shutdown](#shutdown)",
+        initial: "58",
+    },
+    Register {
+        name: "indirect_address_1",
+        address: 49,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 1](#indirect-address)",
+        initial: "634",
+    },
+    Register {
+        name: "indirect_address_2",
+        address: 51,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 2](#indirect-address)",
+        initial: "635",
+    },
+    Register {
+        name: "indirect_address_3",
+        address: 53,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 3](#indirect-address)",
+        initial: "636",
+    },
+    Register {
+        name: "indirect_address_4",
+        address: 55,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 4](#indirect-address)",
+        initial: "637",
+    },
+    Register {
+        name: "indirect_address_5",
+        address: 57,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 5](#indirect-address)",
+        initial: "638",
+    },
+    Register {
+        name: "indirect_address_6",
+        address: 59,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 6](#indirect-address)",
+        initial: "639",
+    },
+    Register {
+        name: "indirect_address_7",
+        address: 61,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 7](#indirect-address)",
+        initial: "640",
+    },
+    Register {
+        name: "indirect_address_8",
+        address: 63,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 8](#indirect-address)",
+        initial: "641",
+    },
+    Register {
+        name: "indirect_address_9",
+        address: 65,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 9](#indirect-address)",
+        initial: "642",
+    },
+    Register {
+        name: "indirect_address_10",
+        address: 67,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 10](#indirect-address)",
+        initial: "643",
+    },
+    Register {
+        name: "indirect_address_11",
+        address: 69,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 11](#indirect-address)",
+        initial: "644",
+    },
+    Register {
+        name: "indirect_address_12",
+        address: 71,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 12](#indirect-address)",
+        initial: "645",
+    },
+    Register {
+        name: "indirect_address_13",
+        address: 73,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 13](#indirect-address)",
+        initial: "646",
+    },
+    Register {
+        name: "indirect_address_14",
+        address: 75,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 14](#indirect-address)",
+        initial: "647",
+    },
+    Register {
+        name: "indirect_address_15",
+        address: 77,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 15](#indirect-address)",
+        initial: "648",
+    },
+    Register {
+        name: "indirect_address_16",
+        address: 79,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 16](#indirect-address)",
+        initial: "649",
+    },
+    Register {
+        name: "indirect_address_17",
+        address: 81,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 17](#indirect-address)",
+        initial: "650",
+    },
+    Register {
+        name: "indirect_address_18",
+        address: 83,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 18](#indirect-address)",
+        initial: "651",
+    },
+    Register {
+        name: "indirect_address_19",
+        address: 85,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 19](#indirect-address)",
+        initial: "652",
+    },
+    Register {
+        name: "indirect_address_20",
+        address: 87,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 20](#indirect-address)",
+        initial: "653",
+    },
+    Register {
+        name: "indirect_address_21",
+        address: 89,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 21](#indirect-address)",
+        initial: "654",
+    },
+    Register {
+        name: "indirect_address_22",
+        address: 91,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 22](#indirect-address)",
+        initial: "655",
+    },
+    Register {
+        name: "indirect_address_23",
+        address: 93,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 23](#indirect-address)",
+        initial: "656",
+    },
+    Register {
+        name: "indirect_address_24",
+        address: 95,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 24](#indirect-address)",
+        initial: "657",
+    },
+    Register {
+        name: "indirect_address_25",
+        address: 97,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 25](#indirect-address)",
+        initial: "658",
+    },
+    Register {
+        name: "indirect_address_26",
+        address: 99,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 26](#indirect-address)",
+        initial: "659",
+    },
+    Register {
+        name: "indirect_address_27",
+        address: 101,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 27](#indirect-address)",
+        initial: "660",
+    },
+    Register {
+        name: "indirect_address_28",
+        address: 103,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 28](#indirect-address)",
+        initial: "661",
+    },
+    Register {
+        name: "indirect_address_29",
+        address: 105,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 29](#indirect-address)",
+        initial: "662",
+    },
+    Register {
+        name: "indirect_address_30",
+        address: 107,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 30](#indirect-address)",
+        initial: "663",
+    },
+    Register {
+        name: "indirect_address_31",
+        address: 109,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 31](#indirect-address)",
+        initial: "664",
+    },
+    Register {
+        name: "indirect_address_32",
+        address: 111,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 32](#indirect-address)",
+        initial: "665",
+    },
+    Register {
+        name: "indirect_address_33",
+        address: 113,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 33](#indirect-address)",
+        initial: "666",
+    },
+    Register {
+        name: "indirect_address_34",
+        address: 115,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 34](#indirect-address)",
+        initial: "667",
+    },
+    Register {
+        name: "indirect_address_35",
+        address: 117,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 35](#indirect-address)",
+        initial: "668",
+    },
+    Register {
+        name: "indirect_address_36",
+        address: 119,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 36](#indirect-address)",
+        initial: "669",
+    },
+    Register {
+        name: "indirect_address_37",
+        address: 121,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 37](#indirect-address)",
+        initial: "670",
+    },
+    Register {
+        name: "indirect_address_38",
+        address: 123,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 38](#indirect-address)",
+        initial: "671",
+    },
+    Register {
+        name: "indirect_address_39",
+        address: 125,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 39](#indirect-address)",
+        initial: "672",
+    },
+    Register {
+        name: "indirect_address_40",
+        address: 127,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 40](#indirect-address)",
+        initial: "673",
+    },
+    Register {
+        name: "indirect_address_41",
+        address: 129,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 41](#indirect-address)",
+        initial: "674",
+    },
+    Register {
+        name: "indirect_address_42",
+        address: 131,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 42](#indirect-address)",
+        initial: "675",
+    },
+    Register {
+        name: "indirect_address_43",
+        address: 133,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 43](#indirect-address)",
+        initial: "676",
+    },
+    Register {
+        name: "indirect_address_44",
+        address: 135,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 44](#indirect-address)",
+        initial: "677",
+    },
+    Register {
+        name: "indirect_address_45",
+        address: 137,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 45](#indirect-address)",
+        initial: "678",
+    },
+    Register {
+        name: "indirect_address_46",
+        address: 139,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 46](#indirect-address)",
+        initial: "679",
+    },
+    Register {
+        name: "indirect_address_47",
+        address: 141,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 47](#indirect-address)",
+        initial: "680",
+    },
+    Register {
+        name: "indirect_address_48",
+        address: 143,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 48](#indirect-address)",
+        initial: "681",
+    },
+    Register {
+        name: "indirect_address_49",
+        address: 145,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 49](#indirect-address)",
+        initial: "682",
+    },
+    Register {
+        name: "indirect_address_50",
+        address: 147,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 50](#indirect-address)",
+        initial: "683",
+    },
+    Register {
+        name: "indirect_address_51",
+        address: 149,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 51](#indirect-address)",
+        initial: "684",
+    },
+    Register {
+        name: "indirect_address_52",
+        address: 151,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 52](#indirect-address)",
+        initial: "685",
+    },
+    Register {
+        name: "indirect_address_53",
+        address: 153,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 53](#indirect-address)",
+        initial: "686",
+    },
+    Register {
+        name: "indirect_address_54",
+        address: 155,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 54](#indirect-address)",
+        initial: "687",
+    },
+    Register {
+        name: "indirect_address_55",
+        address: 157,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 55](#indirect-address)",
+        initial: "688",
+    },
+    Register {
+        name: "indirect_address_56",
+        address: 159,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 56](#indirect-address)",
+        initial: "689",
+    },
+    Register {
+        name: "indirect_address_57",
+        address: 161,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 57](#indirect-address)",
+        initial: "690",
+    },
+    Register {
+        name: "indirect_address_58",
+        address: 163,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 58](#indirect-address)",
+        initial: "691",
+    },
+    Register {
+        name: "indirect_address_59",
+        address: 165,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 59](#indirect-address)",
+        initial: "692",
+    },
+    Register {
+        name: "indirect_address_60",
+        address: 167,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 60](#indirect-address)",
+        initial: "693",
+    },
+    Register {
+        name: "indirect_address_61",
+        address: 169,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 61](#indirect-address)",
+        initial: "694",
+    },
+    Register {
+        name: "indirect_address_62",
+        address: 171,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 62](#indirect-address)",
+        initial: "695",
+    },
+    Register {
+        name: "indirect_address_63",
+        address: 173,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 63](#indirect-address)",
+        initial: "696",
+    },
+    Register {
+        name: "indirect_address_64",
+        address: 175,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 64](#indirect-address)",
+        initial: "697",
+    },
+    Register {
+        name: "indirect_address_65",
+        address: 177,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 65](#indirect-address)",
+        initial: "698",
+    },
+    Register {
+        name: "indirect_address_66",
+        address: 179,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 66](#indirect-address)",
+        initial: "699",
+    },
+    Register {
+        name: "indirect_address_67",
+        address: 181,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 67](#indirect-address)",
+        initial: "700",
+    },
+    Register {
+        name: "indirect_address_68",
+        address: 183,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 68](#indirect-address)",
+        initial: "701",
+    },
+    Register {
+        name: "indirect_address_69",
+        address: 185,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 69](#indirect-address)",
+        initial: "702",
+    },
+    Register {
+        name: "indirect_address_70",
+        address: 187,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 70](#indirect-address)",
+        initial: "703",
+    },
+    Register {
+        name: "indirect_address_71",
+        address: 189,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 71](#indirect-address)",
+        initial: "704",
+    },
+    Register {
+        name: "indirect_address_72",
+        address: 191,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 72](#indirect-address)",
+        initial: "705",
+    },
+    Register {
+        name: "indirect_address_73",
+        address: 193,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 73](#indirect-address)",
+        initial: "706",
+    },
+    Register {
+        name: "indirect_address_74",
+        address: 195,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 74](#indirect-address)",
+        initial: "707",
+    },
+    Register {
+        name: "indirect_address_75",
+        address: 197,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 75](#indirect-address)",
+        initial: "708",
+    },
+    Register {
+        name: "indirect_address_76",
+        address: 199,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 76](#indirect-address)",
+        initial: "709",
+    },
+    Register {
+        name: "indirect_address_77",
+        address: 201,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 77](#indirect-address)",
+        initial: "710",
+    },
+    Register {
+        name: "indirect_address_78",
+        address: 203,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 78](#indirect-address)",
+        initial: "711",
+    },
+    Register {
+        name: "indirect_address_79",
+        address: 205,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 79](#indirect-address)",
+        initial: "712",
+    },
+    Register {
+        name: "indirect_address_80",
+        address: 207,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 80](#indirect-address)",
+        initial: "713",
+    },
+    Register {
+        name: "indirect_address_81",
+        address: 209,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 81](#indirect-address)",
+        initial: "714",
+    },
+    Register {
+        name: "indirect_address_82",
+        address: 211,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 82](#indirect-address)",
+        initial: "715",
+    },
+    Register {
+        name: "indirect_address_83",
+        address: 213,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 83](#indirect-address)",
+        initial: "716",
+    },
+    Register {
+        name: "indirect_address_84",
+        address: 215,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 84](#indirect-address)",
+        initial: "717",
+    },
+    Register {
+        name: "indirect_address_85",
+        address: 217,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 85](#indirect-address)",
+        initial: "718",
+    },
+    Register {
+        name: "indirect_address_86",
+        address: 219,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 86](#indirect-address)",
+        initial: "719",
+    },
+    Register {
+        name: "indirect_address_87",
+        address: 221,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 87](#indirect-address)",
+        initial: "720",
+    },
+    Register {
+        name: "indirect_address_88",
+        address: 223,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 88](#indirect-address)",
+        initial: "721",
+    },
+    Register {
+        name: "indirect_address_89",
+        address: 225,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 89](#indirect-address)",
+        initial: "722",
+    },
+    Register {
+        name: "indirect_address_90",
+        address: 227,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 90](#indirect-address)",
+        initial: "723",
+    },
+    Register {
+        name: "indirect_address_91",
+        address: 229,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 91](#indirect-address)",
+        initial: "724",
+    },
+    Register {
+        name: "indirect_address_92",
+        address: 231,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 92](#indirect-address)",
+        initial: "725",
+    },
+    Register {
+        name: "indirect_address_93",
+        address: 233,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 93](#indirect-address)",
+        initial: "726",
+    },
+    Register {
+        name: "indirect_address_94",
+        address: 235,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 94](#indirect-address)",
+        initial: "727",
+    },
+    Register {
+        name: "indirect_address_95",
+        address: 237,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 95](#indirect-address)",
+        initial: "728",
+    },
+    Register {
+        name: "indirect_address_96",
+        address: 239,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 96](#indirect-address)",
+        initial: "729",
+    },
+    Register {
+        name: "indirect_address_97",
+        address: 241,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 97](#indirect-address)",
+        initial: "730",
+    },
+    Register {
+        name: "indirect_address_98",
+        address: 243,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 98](#indirect-address)",
+        initial: "731",
+    },
+    Register {
+        name: "indirect_address_99",
+        address: 245,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 99](#indirect-address)",
+        initial: "732",
+    },
+    Register {
+        name: "indirect_address_100",
+        address: 247,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 100](#indirect-address)",
+        initial: "733",
+    },
+    Register {
+        name: "indirect_address_101",
+        address: 249,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 101](#indirect-address)",
+        initial: "734",
+    },
+    Register {
+        name: "indirect_address_102",
+        address: 251,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 102](#indirect-address)",
+        initial: "735",
+    },
+    Register {
+        name: "indirect_address_103",
+        address: 253,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 103](#indirect-address)",
+        initial: "736",
+    },
+    Register {
+        name: "indirect_address_104",
+        address: 255,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 104](#indirect-address)",
+        initial: "737",
+    },
+    Register {
+        name: "indirect_address_105",
+        address: 257,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 105](#indirect-address)",
+        initial: "738",
+    },
+    Register {
+        name: "indirect_address_106",
+        address: 259,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 106](#indirect-address)",
+        initial: "739",
+    },
+    Register {
+        name: "indirect_address_107",
+        address: 261,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 107](#indirect-address)",
+        initial: "740",
+    },
+    Register {
+        name: "indirect_address_108",
+        address: 263,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 108](#indirect-address)",
+        initial: "741",
+    },
+    Register {
+        name: "indirect_address_109",
+        address: 265,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 109](#indirect-address)",
+        initial: "742",
+    },
+    Register {
+        name: "indirect_address_110",
+        address: 267,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 110](#indirect-address)",
+        initial: "743",
+    },
+    Register {
+        name: "indirect_address_111",
+        address: 269,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 111](#indirect-address)",
+        initial: "744",
+    },
+    Register {
+        name: "indirect_address_112",
+        address: 271,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 112](#indirect-address)",
+        initial: "745",
+    },
+    Register {
+        name: "indirect_address_113",
+        address: 273,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 113](#indirect-address)",
+        initial: "746",
+    },
+    Register {
+        name: "indirect_address_114",
+        address: 275,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 114](#indirect-address)",
+        initial: "747",
+    },
+    Register {
+        name: "indirect_address_115",
+        address: 277,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 115](#indirect-address)",
+        initial: "748",
+    },
+    Register {
+        name: "indirect_address_116",
+        address: 279,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 116](#indirect-address)",
+        initial: "749",
+    },
+    Register {
+        name: "indirect_address_117",
+        address: 281,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 117](#indirect-address)",
+        initial: "750",
+    },
+    Register {
+        name: "indirect_address_118",
+        address: 283,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 118](#indirect-address)",
+        initial: "751",
+    },
+    Register {
+        name: "indirect_address_119",
+        address: 285,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 119](#indirect-address)",
+        initial: "752",
+    },
+    Register {
+        name: "indirect_address_120",
+        address: 287,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 120](#indirect-address)",
+        initial: "753",
+    },
+    Register {
+        name: "indirect_address_121",
+        address: 289,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 121](#indirect-address)",
+        initial: "754",
+    },
+    Register {
+        name: "indirect_address_122",
+        address: 291,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 122](#indirect-address)",
+        initial: "755",
+    },
+    Register {
+        name: "indirect_address_123",
+        address: 293,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 123](#indirect-address)",
+        initial: "756",
+    },
+    Register {
+        name: "indirect_address_124",
+        address: 295,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 124](#indirect-address)",
+        initial: "757",
+    },
+    Register {
+        name: "indirect_address_125",
+        address: 297,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 125](#indirect-address)",
+        initial: "758",
+    },
+    Register {
+        name: "indirect_address_126",
+        address: 299,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 126](#indirect-address)",
+        initial: "759",
+    },
+    Register {
+        name: "indirect_address_127",
+        address: 301,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 127](#indirect-address)",
+        initial: "760",
+    },
+    Register {
+        name: "indirect_address_128",
+        address: 303,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 128](#indirect-address)",
+        initial: "761",
+    },
+    Register {
+        name: "indirect_address_129",
+        address: 305,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 129](#indirect-address)",
+        initial: "762",
+    },
+    Register {
+        name: "indirect_address_130",
+        address: 307,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 130](#indirect-address)",
+        initial: "763",
+    },
+    Register {
+        name: "indirect_address_131",
+        address: 309,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 131](#indirect-address)",
+        initial: "764",
+    },
+    Register {
+        name: "indirect_address_132",
+        address: 311,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 132](#indirect-address)",
+        initial: "765",
+    },
+    Register {
+        name: "indirect_address_133",
+        address: 313,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 133](#indirect-address)",
+        initial: "766",
+    },
+    Register {
+        name: "indirect_address_134",
+        address: 315,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 134](#indirect-address)",
+        initial: "767",
+    },
+    Register {
+        name: "indirect_address_135",
+        address: 317,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 135](#indirect-address)",
+        initial: "768",
+    },
+    Register {
+        name: "indirect_address_136",
+        address: 319,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 136](#indirect-address)",
+        initial: "769",
+    },
+    Register {
+        name: "indirect_address_137",
+        address: 321,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 137](#indirect-address)",
+        initial: "770",
+    },
+    Register {
+        name: "indirect_address_138",
+        address: 323,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 138](#indirect-address)",
+        initial: "771",
+    },
+    Register {
+        name: "indirect_address_139",
+        address: 325,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 139](#indirect-address)",
+        initial: "772",
+    },
+    Register {
+        name: "indirect_address_140",
+        address: 327,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 140](#indirect-address)",
+        initial: "773",
+    },
+    Register {
+        name: "indirect_address_141",
+        address: 329,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 141](#indirect-address)",
+        initial: "774",
+    },
+    Register {
+        name: "indirect_address_142",
+        address: 331,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 142](#indirect-address)",
+        initial: "775",
+    },
+    Register {
+        name: "indirect_address_143",
+        address: 333,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 143](#indirect-address)",
+        initial: "776",
+    },
+    Register {
+        name: "indirect_address_144",
+        address: 335,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 144](#indirect-address)",
+        initial: "777",
+    },
+    Register {
+        name: "indirect_address_145",
+        address: 337,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 145](#indirect-address)",
+        initial: "778",
+    },
+    Register {
+        name: "indirect_address_146",
+        address: 339,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 146](#indirect-address)",
+        initial: "779",
+    },
+    Register {
+        name: "indirect_address_147",
+        address: 341,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 147](#indirect-address)",
+        initial: "780",
+    },
+    Register {
+        name: "indirect_address_148",
+        address: 343,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 148](#indirect-address)",
+        initial: "781",
+    },
+    Register {
+        name: "indirect_address_149",
+        address: 345,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 149](#indirect-address)",
+        initial: "782",
+    },
+    Register {
+        name: "indirect_address_150",
+        address: 347,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 150](#indirect-address)",
+        initial: "783",
+    },
+    Register {
+        name: "indirect_address_151",
+        address: 349,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 151](#indirect-address)",
+        initial: "784",
+    },
+    Register {
+        name: "indirect_address_152",
+        address: 351,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 152](#indirect-address)",
+        initial: "785",
+    },
+    Register {
+        name: "indirect_address_153",
+        address: 353,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 153](#indirect-address)",
+        initial: "786",
+    },
+    Register {
+        name: "indirect_address_154",
+        address: 355,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 154](#indirect-address)",
+        initial: "787",
+    },
+    Register {
+        name: "indirect_address_155",
+        address: 357,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 155](#indirect-address)",
+        initial: "788",
+    },
+    Register {
+        name: "indirect_address_156",
+        address: 359,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 156](#indirect-address)",
+        initial: "789",
+    },
+    Register {
+        name: "indirect_address_157",
+        address: 361,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 157](#indirect-address)",
+        initial: "790",
+    },
+    Register {
+        name: "indirect_address_158",
+        address: 363,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 158](#indirect-address)",
+        initial: "791",
+    },
+    Register {
+        name: "indirect_address_159",
+        address: 365,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 159](#indirect-address)",
+        initial: "792",
+    },
+    Register {
+        name: "indirect_address_160",
+        address: 367,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 160](#indirect-address)",
+        initial: "793",
+    },
+    Register {
+        name: "indirect_address_161",
+        address: 369,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 161](#indirect-address)",
+        initial: "794",
+    },
+    Register {
+        name: "indirect_address_162",
+        address: 371,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 162](#indirect-address)",
+        initial: "795",
+    },
+    Register {
+        name: "indirect_address_163",
+        address: 373,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 163](#indirect-address)",
+        initial: "796",
+    },
+    Register {
+        name: "indirect_address_164",
+        address: 375,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 164](#indirect-address)",
+        initial: "797",
+    },
+    Register {
+        name: "indirect_address_165",
+        address: 377,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 165](#indirect-address)",
+        initial: "798",
+    },
+    Register {
+        name: "indirect_address_166",
+        address: 379,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 166](#indirect-address)",
+        initial: "799",
+    },
+    Register {
+        name: "indirect_address_167",
+        address: 381,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 167](#indirect-address)",
+        initial: "800",
+    },
+    Register {
+        name: "indirect_address_168",
+        address: 383,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 168](#indirect-address)",
+        initial: "801",
+    },
+    Register {
+        name: "indirect_address_169",
+        address: 385,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 169](#indirect-address)",
+        initial: "802",
+    },
+    Register {
+        name: "indirect_address_170",
+        address: 387,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 170](#indirect-address)",
+        initial: "803",
+    },
+    Register {
+        name: "indirect_address_171",
+        address: 389,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 171](#indirect-address)",
+        initial: "804",
+    },
+    Register {
+        name: "indirect_address_172",
+        address: 391,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 172](#indirect-address)",
+        initial: "805",
+    },
+    Register {
+        name: "indirect_address_173",
+        address: 393,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 173](#indirect-address)",
+        initial: "806",
+    },
+    Register {
+        name: "indirect_address_174",
+        address: 395,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 174](#indirect-address)",
+        initial: "807",
+    },
+    Register {
+        name: "indirect_address_175",
+        address: 397,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 175](#indirect-address)",
+        initial: "808",
+    },
+    Register {
+        name: "indirect_address_176",
+        address: 399,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 176](#indirect-address)",
+        initial: "809",
+    },
+    Register {
+        name: "indirect_address_177",
+        address: 401,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 177](#indirect-address)",
+        initial: "810",
+    },
+    Register {
+        name: "indirect_address_178",
+        address: 403,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 178](#indirect-address)",
+        initial: "811",
+    },
+    Register {
+        name: "indirect_address_179",
+        address: 405,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 179](#indirect-address)",
+        initial: "812",
+    },
+    Register {
+        name: "indirect_address_180",
+        address: 407,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 180](#indirect-address)",
+        initial: "813",
+    },
+    Register {
+        name: "indirect_address_181",
+        address: 409,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 181](#indirect-address)",
+        initial: "814",
+    },
+    Register {
+        name: "indirect_address_182",
+        address: 411,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 182](#indirect-address)",
+        initial: "815",
+    },
+    Register {
+        name: "indirect_address_183",
+        address: 413,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 183](#indirect-address)",
+        initial: "816",
+    },
+    Register {
+        name: "indirect_address_184",
+        address: 415,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 184](#indirect-address)",
+        initial: "817",
+    },
+    Register {
+        name: "indirect_address_185",
+        address: 417,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 185](#indirect-address)",
+        initial: "818",
+    },
+    Register {
+        name: "indirect_address_186",
+        address: 419,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 186](#indirect-address)",
+        initial: "819",
+    },
+    Register {
+        name: "indirect_address_187",
+        address: 421,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 187](#indirect-address)",
+        initial: "820",
+    },
+    Register {
+        name: "indirect_address_188",
+        address: 423,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 188](#indirect-address)",
+        initial: "821",
+    },
+    Register {
+        name: "indirect_address_189",
+        address: 425,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 189](#indirect-address)",
+        initial: "822",
+    },
+    Register {
+        name: "indirect_address_190",
+        address: 427,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 190](#indirect-address)",
+        initial: "823",
+    },
+    Register {
+        name: "indirect_address_191",
+        address: 429,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 191](#indirect-address)",
+        initial: "824",
+    },
+    Register {
+        name: "indirect_address_192",
+        address: 431,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 192](#indirect-address)",
+        initial: "825",
+    },
+    Register {
+        name: "indirect_address_193",
+        address: 433,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 193](#indirect-address)",
+        initial: "826",
+    },
+    Register {
+        name: "indirect_address_194",
+        address: 435,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 194](#indirect-address)",
+        initial: "827",
+    },
+    Register {
+        name: "indirect_address_195",
+        address: 437,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 195](#indirect-address)",
+        initial: "828",
+    },
+    Register {
+        name: "indirect_address_196",
+        address: 439,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 196](#indirect-address)",
+        initial: "829",
+    },
+    Register {
+        name: "indirect_address_197",
+        address: 441,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 197](#indirect-address)",
+        initial: "830",
+    },
+    Register {
+        name: "indirect_address_198",
+        address: 443,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 198](#indirect-address)",
+        initial: "831",
+    },
+    Register {
+        name: "indirect_address_199",
+        address: 445,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 199](#indirect-address)",
+        initial: "832",
+    },
+    Register {
+        name: "indirect_address_200",
+        address: 447,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 200](#indirect-address)",
+        initial: "833",
+    },
+    Register {
+        name: "indirect_address_201",
+        address: 449,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 201](#indirect-address)",
+        initial: "834",
+    },
+    Register {
+        name: "indirect_address_202",
+        address: 451,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 202](#indirect-address)",
+        initial: "835",
+    },
+    Register {
+        name: "indirect_address_203",
+        address: 453,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 203](#indirect-address)",
+        initial: "836",
+    },
+    Register {
+        name: "indirect_address_204",
+        address: 455,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 204](#indirect-address)",
+        initial: "837",
+    },
+    Register {
+        name: "indirect_address_205",
+        address: 457,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 205](#indirect-address)",
+        initial: "838",
+    },
+    Register {
+        name: "indirect_address_206",
+        address: 459,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 206](#indirect-address)",
+        initial: "839",
+    },
+    Register {
+        name: "indirect_address_207",
+        address: 461,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 207](#indirect-address)",
+        initial: "840",
+    },
+    Register {
+        name: "indirect_address_208",
+        address: 463,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 208](#indirect-address)",
+        initial: "841",
+    },
+    Register {
+        name: "indirect_address_209",
+        address: 465,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 209](#indirect-address)",
+        initial: "842",
+    },
+    Register {
+        name: "indirect_address_210",
+        address: 467,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 210](#indirect-address)",
+        initial: "843",
+    },
+    Register {
+        name: "indirect_address_211",
+        address: 469,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 211](#indirect-address)",
+        initial: "844",
+    },
+    Register {
+        name: "indirect_address_212",
+        address: 471,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 212](#indirect-address)",
+        initial: "845",
+    },
+    Register {
+        name: "indirect_address_213",
+        address: 473,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 213](#indirect-address)",
+        initial: "846",
+    },
+    Register {
+        name: "indirect_address_214",
+        address: 475,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 214](#indirect-address)",
+        initial: "847",
+    },
+    Register {
+        name: "indirect_address_215",
+        address: 477,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 215](#indirect-address)",
+        initial: "848",
+    },
+    Register {
+        name: "indirect_address_216",
+        address: 479,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 216](#indirect-address)",
+        initial: "849",
+    },
+    Register {
+        name: "indirect_address_217",
+        address: 481,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 217](#indirect-address)",
+        initial: "850",
+    },
+    Register {
+        name: "indirect_address_218",
+        address: 483,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 218](#indirect-address)",
+        initial: "851",
+    },
+    Register {
+        name: "indirect_address_219",
+        address: 485,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 219](#indirect-address)",
+        initial: "852",
+    },
+    Register {
+        name: "indirect_address_220",
+        address: 487,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 220](#indirect-address)",
+        initial: "853",
+    },
+    Register {
+        name: "indirect_address_221",
+        address: 489,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 221](#indirect-address)",
+        initial: "854",
+    },
+    Register {
+        name: "indirect_address_222",
+        address: 491,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 222](#indirect-address)",
+        initial: "855",
+    },
+    Register {
+        name: "indirect_address_223",
+        address: 493,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 223](#indirect-address)",
+        initial: "856",
+    },
+    Register {
+        name: "indirect_address_224",
+        address: 495,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 224](#indirect-address)",
+        initial: "857",
+    },
+    Register {
+        name: "indirect_address_225",
+        address: 497,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 225](#indirect-address)",
+        initial: "858",
+    },
+    Register {
+        name: "indirect_address_226",
+        address: 499,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 226](#indirect-address)",
+        initial: "859",
+    },
+    Register {
+        name: "indirect_address_227",
+        address: 501,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 227](#indirect-address)",
+        initial: "860",
+    },
+    Register {
+        name: "indirect_address_228",
+        address: 503,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 228](#indirect-address)",
+        initial: "861",
+    },
+    Register {
+        name: "indirect_address_229",
+        address: 505,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 229](#indirect-address)",
+        initial: "862",
+    },
+    Register {
+        name: "indirect_address_230",
+        address: 507,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 230](#indirect-address)",
+        initial: "863",
+    },
+    Register {
+        name: "indirect_address_231",
+        address: 509,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 231](#indirect-address)",
+        initial: "864",
+    },
+    Register {
+        name: "indirect_address_232",
+        address: 511,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 232](#indirect-address)",
+        initial: "865",
+    },
+    Register {
+        name: "indirect_address_233",
+        address: 513,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 233](#indirect-address)",
+        initial: "866",
+    },
+    Register {
+        name: "indirect_address_234",
+        address: 515,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 234](#indirect-address)",
+        initial: "867",
+    },
+    Register {
+        name: "indirect_address_235",
+        address: 517,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 235](#indirect-address)",
+        initial: "868",
+    },
+    Register {
+        name: "indirect_address_236",
+        address: 519,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 236](#indirect-address)",
+        initial: "869",
+    },
+    Register {
+        name: "indirect_address_237",
+        address: 521,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 237](#indirect-address)",
+        initial: "870",
+    },
+    Register {
+        name: "indirect_address_238",
+        address: 523,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 238](#indirect-address)",
+        initial: "871",
+    },
+    Register {
+        name: "indirect_address_239",
+        address: 525,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 239](#indirect-address)",
+        initial: "872",
+    },
+    Register {
+        name: "indirect_address_240",
+        address: 527,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 240](#indirect-address)",
+        initial: "873",
+    },
+    Register {
+        name: "indirect_address_241",
+        address: 529,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 241](#indirect-address)",
+        initial: "874",
+    },
+    Register {
+        name: "indirect_address_242",
+        address: 531,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 242](#indirect-address)",
+        initial: "875",
+    },
+    Register {
+        name: "indirect_address_243",
+        address: 533,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 243](#indirect-address)",
+        initial: "876",
+    },
+    Register {
+        name: "indirect_address_244",
+        address: 535,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 244](#indirect-address)",
+        initial: "877",
+    },
+    Register {
+        name: "indirect_address_245",
+        address: 537,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 245](#indirect-address)",
+        initial: "878",
+    },
+    Register {
+        name: "indirect_address_246",
+        address: 539,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 246](#indirect-address)",
+        initial: "879",
+    },
+    Register {
+        name: "indirect_address_247",
+        address: 541,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 247](#indirect-address)",
+        initial: "880",
+    },
+    Register {
+        name: "indirect_address_248",
+        address: 543,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 248](#indirect-address)",
+        initial: "881",
+    },
+    Register {
+        name: "indirect_address_249",
+        address: 545,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 249](#indirect-address)",
+        initial: "882",
+    },
+    Register {
+        name: "indirect_address_250",
+        address: 547,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 250](#indirect-address)",
+        initial: "883",
+    },
+    Register {
+        name: "indirect_address_251",
+        address: 549,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 251](#indirect-address)",
+        initial: "884",
+    },
+    Register {
+        name: "indirect_address_252",
+        address: 551,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 252](#indirect-address)",
+        initial: "885",
+    },
+    Register {
+        name: "indirect_address_253",
+        address: 553,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 253](#indirect-address)",
+        initial: "886",
+    },
+    Register {
+        name: "indirect_address_254",
+        address: 555,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 254](#indirect-address)",
+        initial: "887",
+    },
+    Register {
+        name: "indirect_address_255",
+        address: 557,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 255](#indirect-address)",
+        initial: "888",
+    },
+    Register {
+        name: "indirect_address_256",
+        address: 559,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 256](#indirect-address)",
+        initial: "889",
+    },
+    Register {
+        name: "torque_enable",
+        address: 562,
+        size: 1,
+        writable: true,
+        description: "[Torque Enable](#torque-enable)",
+        initial: "0",
+    },
+    Register {
+        name: "led_red",
+        address: 563,
+        size: 1,
+        writable: true,
+        description: "[LED Red](#led)",
+        initial: "0",
+    },
+    Register {
+        name: "led_green",
+        address: 564,
+        size: 1,
+        writable: true,
+        description: "[LED Green](#led)",
+        initial: "0",
+    },
+    Register {
+        name: "led_blue",
+        address: 565,
+        size: 1,
+        writable: true,
+        description: "[LED Blue](#led)",
+        initial: "0",
+    },
+    Register {
+        name: "velocity_i_gain",
+        address: 586,
+        size: 2,
+        writable: true,
+        description: "[Velocity I Gain](#velocity-i-gain)",
+        initial: "40",
+    },
+    Register {
+        name: "velocity_p_gain",
+        address: 588,
+        size: 2,
+        writable: true,
+        description: "[Velocity P Gain](#velocity-p-gain)",
+        initial: "440",
+    },
+    Register {
+        name: "position_p_gain",
+        address: 594,
+        size: 2,
+        writable: true,
+        description: "[Position P Gain](#position-p-gain)",
+        initial: "32",
+    },
+    Register {
+        name: "goal_position",
+        address: 596,
+        size: 4,
+        writable: true,
+        description: "[Goal Position](#goal-position)",
+        initial: "-",
+    },
+    Register {
+        name: "goal_velocity",
+        address: 600,
+        size: 4,
+        writable: true,
+        description: "[Goal Velocity](#goal-velocity)",
+        initial: "0",
+    },
+    Register {
+        name: "goal_torque",
+        address: 604,
+        size: 2,
+        writable: true,
+        description: "[Goal Torque](#goal-torque)",
+        initial: "0",
+    },
+    Register {
+        name: "goal_acceleration",
+        address: 606,
+        size: 4,
+        writable: true,
+        description: "[Goal Acceleration](#goal-acceleration)",
+        initial: "0",
+    },
+    Register {
+        name: "moving",
+        address: 610,
+        size: 1,
+        writable: false,
+        description: "[Moving](#moving)",
+        initial: "-",
+    },
+    Register {
+        name: "present_position",
+        address: 611,
+        size: 4,
+        writable: false,
+        description: "[Present Position](#present-position)",
+        initial: "-",
+    },
+    Register {
+        name: "present_velocity",
+        address: 615,
+        size: 4,
+        writable: false,
+        description: "[Present Velocity](#present-velocity)",
+        initial: "-",
+    },
+    Register {
+        name: "present_current",
+        address: 621,
+        size: 2,
+        writable: false,
+        description: "[Present Current](#present-current)",
+        initial: "-",
+    },
+    Register {
+        name: "present_input_voltage",
+        address: 623,
+        size: 2,
+        writable: false,
+        description: "[Present Input Voltage](#present-input-voltage)",
+        initial: "-",
+    },
+    Register {
+        name: "present_temperature",
+        address: 625,
+        size: 1,
+        writable: false,
+        description: "[Present Temperature](#present-temperature)",
+        initial: "-",
+    },
+    Register {
+        name: "external_port_data_1",
+        address: 626,
+        size: 2,
+        writable: false,
+        description: "[External Port Data 1](#external-port-data)",
+        initial: "0",
+    },
+    Register {
+        name: "external_port_data_2",
+        address: 628,
+        size: 2,
+        writable: false,
+        description: "[External Port Data 2](#external-port-data)",
+        initial: "0",
+    },
+    Register {
+        name: "external_port_data_3",
+        address: 630,
+        size: 2,
+        writable: false,
+        description: "[External Port Data 3](#external-port-data)",
+        initial: "0",
+    },
+    Register {
+        name: "external_port_data_4",
+        address: 632,
+        size: 2,
+        writable: false,
+        description: "[External Port Data 4](#external-port-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_1",
+        address: 634,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 1](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_2",
+        address: 635,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 2](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_3",
+        address: 636,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 3](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_4",
+        address: 637,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 4](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_5",
+        address: 638,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 5](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_6",
+        address: 639,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 6](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_7",
+        address: 640,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 7](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_8",
+        address: 641,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 8](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_9",
+        address: 642,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 9](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_10",
+        address: 643,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 10](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_11",
+        address: 644,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 11](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_12",
+        address: 645,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 12](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_13",
+        address: 646,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 13](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_14",
+        address: 647,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 14](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_15",
+        address: 648,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 15](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_16",
+        address: 649,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 16](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_17",
+        address: 650,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 17](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_18",
+        address: 651,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 18](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_19",
+        address: 652,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 19](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_20",
+        address: 653,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 20](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_21",
+        address: 654,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 21](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_22",
+        address: 655,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 22](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_23",
+        address: 656,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 23](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_24",
+        address: 657,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 24](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_25",
+        address: 658,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 25](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_26",
+        address: 659,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 26](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_27",
+        address: 660,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 27](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_28",
+        address: 661,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 28](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_29",
+        address: 662,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 29](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_30",
+        address: 663,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 30](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_31",
+        address: 664,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 31](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_32",
+        address: 665,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 32](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_33",
+        address: 666,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 33](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_34",
+        address: 667,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 34](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_35",
+        address: 668,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 35](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_36",
+        address: 669,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 36](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_37",
+        address: 670,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 37](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_38",
+        address: 671,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 38](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_39",
+        address: 672,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 39](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_40",
+        address: 673,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 40](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_41",
+        address: 674,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 41](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_42",
+        address: 675,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 42](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_43",
+        address: 676,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 43](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_44",
+        address: 677,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 44](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_45",
+        address: 678,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 45](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_46",
+        address: 679,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 46](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_47",
+        address: 680,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 47](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_48",
+        address: 681,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 48](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_49",
+        address: 682,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 49](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_50",
+        address: 683,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 50](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_51",
+        address: 684,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 51](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_52",
+        address: 685,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 52](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_53",
+        address: 686,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 53](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_54",
+        address: 687,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 54](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_55",
+        address: 688,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 55](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_56",
+        address: 689,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 56](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_57",
+        address: 690,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 57](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_58",
+        address: 691,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 58](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_59",
+        address: 692,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 59](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_60",
+        address: 693,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 60](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_61",
+        address: 694,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 61](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_62",
+        address: 695,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 62](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_63",
+        address: 696,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 63](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_64",
+        address: 697,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 64](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_65",
+        address: 698,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 65](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_66",
+        address: 699,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 66](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_67",
+        address: 700,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 67](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_68",
+        address: 701,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 68](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_69",
+        address: 702,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 69](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_70",
+        address: 703,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 70](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_71",
+        address: 704,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 71](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_72",
+        address: 705,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 72](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_73",
+        address: 706,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 73](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_74",
+        address: 707,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 74](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_75",
+        address: 708,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 75](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_76",
+        address: 709,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 76](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_77",
+        address: 710,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 77](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_78",
+        address: 711,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 78](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_79",
+        address: 712,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 79](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_80",
+        address: 713,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 80](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_81",
+        address: 714,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 81](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_82",
+        address: 715,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 82](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_83",
+        address: 716,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 83](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_84",
+        address: 717,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 84](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_85",
+        address: 718,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 85](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_86",
+        address: 719,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 86](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_87",
+        address: 720,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 87](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_88",
+        address: 721,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 88](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_89",
+        address: 722,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 89](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_90",
+        address: 723,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 90](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_91",
+        address: 724,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 91](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_92",
+        address: 725,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 92](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_93",
+        address: 726,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 93](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_94",
+        address: 727,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 94](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_95",
+        address: 728,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 95](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_96",
+        address: 729,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 96](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_97",
+        address: 730,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 97](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_98",
+        address: 731,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 98](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_99",
+        address: 732,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 99](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_100",
+        address: 733,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 100](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_101",
+        address: 734,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 101](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_102",
+        address: 735,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 102](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_103",
+        address: 736,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 103](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_104",
+        address: 737,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 104](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_105",
+        address: 738,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 105](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_106",
+        address: 739,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 106](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_107",
+        address: 740,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 107](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_108",
+        address: 741,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 108](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_109",
+        address: 742,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 109](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_110",
+        address: 743,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 110](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_111",
+        address: 744,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 111](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_112",
+        address: 745,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 112](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_113",
+        address: 746,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 113](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_114",
+        address: 747,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 114](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_115",
+        address: 748,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 115](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_116",
+        address: 749,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 116](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_117",
+        address: 750,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 117](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_118",
+        address: 751,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 118](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_119",
+        address: 752,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 119](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_120",
+        address: 753,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 120](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_121",
+        address: 754,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 121](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_122",
+        address: 755,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 122](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_123",
+        address: 756,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 123](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_124",
+        address: 757,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 124](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_125",
+        address: 758,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 125](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_126",
+        address: 759,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 126](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_127",
+        address: 760,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 127](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_128",
+        address: 761,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 128](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_129",
+        address: 762,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 129](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_130",
+        address: 763,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 130](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_131",
+        address: 764,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 131](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_132",
+        address: 765,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 132](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_133",
+        address: 766,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 133](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_134",
+        address: 767,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 134](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_135",
+        address: 768,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 135](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_136",
+        address: 769,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 136](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_137",
+        address: 770,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 137](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_138",
+        address: 771,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 138](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_139",
+        address: 772,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 139](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_140",
+        address: 773,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 140](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_141",
+        address: 774,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 141](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_142",
+        address: 775,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 142](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_143",
+        address: 776,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 143](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_144",
+        address: 777,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 144](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_145",
+        address: 778,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 145](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_146",
+        address: 779,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 146](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_147",
+        address: 780,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 147](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_148",
+        address: 781,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 148](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_149",
+        address: 782,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 149](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_150",
+        address: 783,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 150](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_151",
+        address: 784,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 151](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_152",
+        address: 785,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 152](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_153",
+        address: 786,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 153](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_154",
+        address: 787,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 154](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_155",
+        address: 788,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 155](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_156",
+        address: 789,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 156](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_157",
+        address: 790,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 157](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_158",
+        address: 791,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 158](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_159",
+        address: 792,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 159](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_160",
+        address: 793,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 160](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_161",
+        address: 794,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 161](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_162",
+        address: 795,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 162](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_163",
+        address: 796,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 163](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_164",
+        address: 797,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 164](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_165",
+        address: 798,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 165](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_166",
+        address: 799,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 166](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_167",
+        address: 800,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 167](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_168",
+        address: 801,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 168](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_169",
+        address: 802,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 169](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_170",
+        address: 803,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 170](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_171",
+        address: 804,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 171](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_172",
+        address: 805,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 172](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_173",
+        address: 806,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 173](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_174",
+        address: 807,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 174](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_175",
+        address: 808,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 175](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_176",
+        address: 809,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 176](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_177",
+        address: 810,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 177](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_178",
+        address: 811,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 178](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_179",
+        address: 812,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 179](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_180",
+        address: 813,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 180](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_181",
+        address: 814,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 181](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_182",
+        address: 815,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 182](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_183",
+        address: 816,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 183](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_184",
+        address: 817,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 184](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_185",
+        address: 818,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 185](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_186",
+        address: 819,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 186](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_187",
+        address: 820,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 187](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_188",
+        address: 821,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 188](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_189",
+        address: 822,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 189](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_190",
+        address: 823,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 190](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_191",
+        address: 824,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 191](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_192",
+        address: 825,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 192](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_193",
+        address: 826,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 193](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_194",
+        address: 827,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 194](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_195",
+        address: 828,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 195](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_196",
+        address: 829,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 196](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_197",
+        address: 830,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 197](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_198",
+        address: 831,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 198](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_199",
+        address: 832,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 199](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_200",
+        address: 833,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 200](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_201",
+        address: 834,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 201](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_202",
+        address: 835,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 202](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_203",
+        address: 836,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 203](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_204",
+        address: 837,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 204](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_205",
+        address: 838,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 205](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_206",
+        address: 839,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 206](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_207",
+        address: 840,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 207](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_208",
+        address: 841,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 208](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_209",
+        address: 842,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 209](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_210",
+        address: 843,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 210](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_211",
+        address: 844,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 211](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_212",
+        address: 845,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 212](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_213",
+        address: 846,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 213](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_214",
+        address: 847,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 214](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_215",
+        address: 848,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 215](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_216",
+        address: 849,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 216](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_217",
+        address: 850,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 217](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_218",
+        address: 851,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 218](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_219",
+        address: 852,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 219](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_220",
+        address: 853,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 220](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_221",
+        address: 854,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 221](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_222",
+        address: 855,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 222](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_223",
+        address: 856,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 223](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_224",
+        address: 857,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 224](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_225",
+        address: 858,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 225](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_226",
+        address: 859,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 226](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_227",
+        address: 860,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 227](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_228",
+        address: 861,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 228](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_229",
+        address: 862,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 229](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_230",
+        address: 863,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 230](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_231",
+        address: 864,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 231](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_232",
+        address: 865,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 232](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_233",
+        address: 866,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 233](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_234",
+        address: 867,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 234](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_235",
+        address: 868,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 235](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_236",
+        address: 869,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 236](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_237",
+        address: 870,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 237](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_238",
+        address: 871,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 238](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_239",
+        address: 872,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 239](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_240",
+        address: 873,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 240](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_241",
+        address: 874,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 241](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_242",
+        address: 875,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 242](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_243",
+        address: 876,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 243](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_244",
+        address: 877,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 244](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_245",
+        address: 878,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 245](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_246",
+        address: 879,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 246](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_247",
+        address: 880,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 247](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_248",
+        address: 881,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 248](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_249",
+        address: 882,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 249](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_250",
+        address: 883,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 250](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_251",
+        address: 884,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 251](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_252",
+        address: 885,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 252](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_253",
+        address: 886,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 253](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_254",
+        address: 887,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 254](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_255",
+        address: 888,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 255](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_256",
+        address: 889,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 256](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "registered_instruction",
+        address: 890,
+        size: 1,
+        writable: false,
+        description: "[Registered Instruction](#registered-instruction)",
+        initial: "0",
+    },
+    Register {
+        name: "status_return_level",
+        address: 891,
+        size: 1,
+        writable: true,
+        description: "[Status Return Level](#status-return-level)",
+        initial: "2",
+    },
+    Register {
+        name: "hardware_error_status",
+        address: 892,
+        size: 1,
+        writable: false,
+        description: "[Hardware Error Status](#hardware-error-status)",
+        initial: "0",
+    },
+];
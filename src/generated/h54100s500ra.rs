@@ -1,2531 +1,3963 @@
-use crate::protocol::{Controller, Error, Instruction, Protocol, StatusPacket};
-use embedded_hal::{digital::v2::OutputPin, serial};
-use heapless::Vec;
+use crate::control_table::Register;
+use crate::protocol::{Controller, Error, Protocol, StatusPacket};
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+use embedded_io::{Read, ReadReady, Write};
 
 pub trait H54100S500RA<Serial, const PROTOCOL_VERSION: u8>:
     Protocol<Serial, PROTOCOL_VERSION>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
 {
     /// [Model Number](#model-number) (initial: 53,769)
     fn get_h54100s500ra_model_number(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(0).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 0)?))
     }
     /// [Model Information](#model-information) (initial: -)
     fn get_h54100s500ra_model_information(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 2)?))
     }
     /// [Firmware Version](#firmware-version) (initial: -)
     fn get_h54100s500ra_firmware_version(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(6).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 6)?))
     }
     /// [ID](#id) (initial: 1)
     fn get_h54100s500ra_id(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(7).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 7)?))
     }
     fn set_h54100s500ra_id(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(7).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 7, &params.to_le_bytes())
     }
     /// [Baud Rate](#baud-rate) (initial: 1)
     fn get_h54100s500ra_baud_rate(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(8).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 8)?))
     }
     fn set_h54100s500ra_baud_rate(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(8).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 8, &params.to_le_bytes())
     }
     /// [Return Delay Time](#return-delay-time) (initial: 250)
     fn get_h54100s500ra_return_delay_time(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(9).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 9)?))
     }
     fn set_h54100s500ra_return_delay_time(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(9).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 9, &params.to_le_bytes())
     }
     /// [Drive Mode](#drive-mode) (initial: 0)
     fn get_h54100s500ra_drive_mode(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(10).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 10)?))
     }
     fn set_h54100s500ra_drive_mode(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(10).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 10, &params.to_le_bytes())
     }
     /// [Operating Mode](#operating-mode) (initial: 3)
     fn get_h54100s500ra_operating_mode(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(11).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 11)?))
     }
     fn set_h54100s500ra_operating_mode(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(11).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 11, &params.to_le_bytes())
     }
     /// [Secondary ID](#secondary-id) (initial: 255)
     fn get_h54100s500ra_secondary_id(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(12).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 12)?))
     }
     fn set_h54100s500ra_secondary_id(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(12).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 12, &params.to_le_bytes())
     }
     /// [Homing Offset](#homing-offset) (initial: 0)
     fn get_h54100s500ra_homing_offset(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(20).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 20)?))
     }
     fn set_h54100s500ra_homing_offset(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(20).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 20, &params.to_le_bytes())
     }
     /// [Moving Threshold](#moving-threshold) (initial: 50)
     fn get_h54100s500ra_moving_threshold(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(24).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 24)?))
     }
     fn set_h54100s500ra_moving_threshold(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(24).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 24, &params.to_le_bytes())
     }
     /// [Temperature Limit](#temperature-limit) (initial: 80)
     fn get_h54100s500ra_temperature_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(31).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 31)?))
     }
     fn set_h54100s500ra_temperature_limit(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(31).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 31, &params.to_le_bytes())
     }
     /// [Max Voltage Limit](#max-voltage-limit) (initial: 350)
     fn get_h54100s500ra_max_voltage_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(32).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 32)?))
     }
     fn set_h54100s500ra_max_voltage_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(32).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 32, &params.to_le_bytes())
     }
     /// [Min Voltage Limit](#min-voltage-limit) (initial: 150)
     fn get_h54100s500ra_min_voltage_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(34).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 34)?))
     }
     fn set_h54100s500ra_min_voltage_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(34).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 34, &params.to_le_bytes())
     }
     /// [PWM Limit](#pwm-limit) (initial: 2,009)
     fn get_h54100s500ra_pwm_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(36).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 36)?))
     }
     fn set_h54100s500ra_pwm_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(36).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 36, &params.to_le_bytes())
     }
     /// [Current Limit](#current-limit) (initial: 15,900)
     fn get_h54100s500ra_current_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(38).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 38)?))
     }
     fn set_h54100s500ra_current_limit(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(38).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 38, &params.to_le_bytes())
     }
     /// [Acceleration Limit](#acceleration-limit) (initial: 10,639)
     fn get_h54100s500ra_acceleration_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(40).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 40)?))
     }
     fn set_h54100s500ra_acceleration_limit(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(40).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 40, &params.to_le_bytes())
     }
     /// [Velocity Limit](#velocity-limit) (initial: 2,920)
     fn get_h54100s500ra_velocity_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(44).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 44)?))
     }
     fn set_h54100s500ra_velocity_limit(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(44).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 44, &params.to_le_bytes())
     }
     /// [Max Position Limit](#max-position-limit) (initial: 501,433)
     fn get_h54100s500ra_max_position_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(48).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 48)?))
     }
     fn set_h54100s500ra_max_position_limit(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(48).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 48, &params.to_le_bytes())
     }
     /// [Min Position Limit](#min-position-limit) (initial: -501,433)
     fn get_h54100s500ra_min_position_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(52).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 52)?))
     }
     fn set_h54100s500ra_min_position_limit(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(52).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 52, &params.to_le_bytes())
     }
     /// [External Port Mode 1](#external-port-mode) (initial: 3)
     fn get_h54100s500ra_external_port_mode_1(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(56).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 56)?))
     }
     fn set_h54100s500ra_external_port_mode_1(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(56).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 56, &params.to_le_bytes())
     }
     /// [External Port Mode 2](#external-port-mode) (initial: 3)
     fn get_h54100s500ra_external_port_mode_2(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(57).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 57)?))
     }
     fn set_h54100s500ra_external_port_mode_2(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(57).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 57, &params.to_le_bytes())
     }
     /// [External Port Mode 3](#external-port-mode) (initial: 3)
     fn get_h54100s500ra_external_port_mode_3(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(58).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 58)?))
     }
     fn set_h54100s500ra_external_port_mode_3(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(58).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 58, &params.to_le_bytes())
     }
     /// [External Port Mode 4](#external-port-mode) (initial: 3)
     fn get_h54100s500ra_external_port_mode_4(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(59).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 59)?))
     }
     fn set_h54100s500ra_external_port_mode_4(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(59).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 59, &params.to_le_bytes())
     }
     /// [Shutdown](#shutdown) (initial: 52)
     fn get_h54100s500ra_shutdown(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(63).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 63)?))
     }
     fn set_h54100s500ra_shutdown(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(63).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 63, &params.to_le_bytes())
     }
     /// [Indirect Address 1](#indirect-address) (initial: 634)
     fn get_h54100s500ra_indirect_address_1(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(168).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 168)?))
     }
     fn set_h54100s500ra_indirect_address_1(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(168).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 168, &params.to_le_bytes())
     }
     /// [Indirect Address 2](#indirect-address) (initial: 635)
     fn get_h54100s500ra_indirect_address_2(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(170).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 170)?))
     }
     fn set_h54100s500ra_indirect_address_2(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(170).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 170, &params.to_le_bytes())
     }
     /// [Indirect Address 3](#indirect-address) (initial: 636)
     fn get_h54100s500ra_indirect_address_3(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(172).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 172)?))
     }
     fn set_h54100s500ra_indirect_address_3(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(172).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 172, &params.to_le_bytes())
     }
     /// [Indirect Address 128](#indirect-address) (initial: 761)
     fn get_h54100s500ra_indirect_address_128(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(166).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(1).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 422)?))
     }
     fn set_h54100s500ra_indirect_address_128(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(166).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(1).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 422, &params.to_le_bytes())
     }
     /// [Torque Enable](#torque-enable) (initial: 0)
     fn get_h54100s500ra_torque_enable(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(0).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 512)?))
     }
     fn set_h54100s500ra_torque_enable(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(0).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 512, &params.to_le_bytes())
     }
     /// [LED Red](#led) (initial: 0)
     fn get_h54100s500ra_led_red(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 513)?))
     }
     fn set_h54100s500ra_led_red(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 513, &params.to_le_bytes())
     }
     /// [LED Green](#led) (initial: 0)
     fn get_h54100s500ra_led_green(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 514)?))
     }
     fn set_h54100s500ra_led_green(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 514, &params.to_le_bytes())
     }
     /// [LED Blue](#led) (initial: 0)
     fn get_h54100s500ra_led_blue(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(3).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 515)?))
     }
     fn set_h54100s500ra_led_blue(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(3).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 515, &params.to_le_bytes())
     }
     /// [Status Return Level](#status-return-level) (initial: 2)
     fn get_h54100s500ra_status_return_level(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        let level = u8::from_le_bytes(self.read(id, 516)?);
+        self.set_status_return_level(id, level.into());
+        Ok(level)
     }
     fn set_h54100s500ra_status_return_level(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        let status = self.write::<3, 1>(id, 516, &params.to_le_bytes())?;
+        self.set_status_return_level(id, params.into());
+        Ok(status)
     }
     /// [Registered Instruction](#registered-instruction) (initial: 0)
     fn get_h54100s500ra_registered_instruction(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(5).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 517)?))
     }
     /// [Hardware Error Status](#hardware-error-status) (initial: 0)
     fn get_h54100s500ra_hardware_error_status(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(6).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 518)?))
     }
     /// [Velocity I Gain](#velocity-i-gain) (initial: -)
     fn get_h54100s500ra_velocity_i_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(12).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 524)?))
     }
     fn set_h54100s500ra_velocity_i_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(12).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 524, &params.to_le_bytes())
     }
     /// [Velocity P Gain](#velocity-p-gain) (initial: -)
     fn get_h54100s500ra_velocity_p_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(14).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 526)?))
     }
     fn set_h54100s500ra_velocity_p_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(14).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 526, &params.to_le_bytes())
     }
     /// [Position D Gain](#position-p-gain) (initial: -)
     fn get_h54100s500ra_position_d_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(16).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 528)?))
     }
     fn set_h54100s500ra_position_d_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(16).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 528, &params.to_le_bytes())
     }
     /// [Position P Gain](#position-p-gain) (initial: -)
     fn get_h54100s500ra_position_p_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(20).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 532)?))
     }
     fn set_h54100s500ra_position_p_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(20).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 532, &params.to_le_bytes())
     }
     /// [Position I Gain](#position-p-gain) (initial: -)
     fn get_h54100s500ra_position_i_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(18).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 530)?))
     }
     fn set_h54100s500ra_position_i_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(18).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 530, &params.to_le_bytes())
     }
     /// [Feedforward 2nd Gain](#feedforward-2nd-gain) (initial: -)
     fn get_h54100s500ra_feedforward_2nd_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(24).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 536)?))
     }
     fn set_h54100s500ra_feedforward_2nd_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(24).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 536, &params.to_le_bytes())
     }
     /// [Feedforward 1st Gain](#feedforward-1st-gain) (initial: -)
     fn get_h54100s500ra_feedforward_1st_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(26).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 538)?))
     }
     fn set_h54100s500ra_feedforward_1st_gain(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(26).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 538, &params.to_le_bytes())
     }
     /// [Bus Watchdog](#bus-watchdog) (initial: -)
     fn get_h54100s500ra_bus_watchdog(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(34).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 546)?))
     }
     fn set_h54100s500ra_bus_watchdog(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(34).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 546, &params.to_le_bytes())
     }
     /// [Goal PWM](#goal-pwm) (initial: -)
     fn get_h54100s500ra_goal_pwm(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(36).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 548)?))
     }
     fn set_h54100s500ra_goal_pwm(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(36).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 548, &params.to_le_bytes())
     }
     /// [Goal Current](#goal-current) (initial: -)
     fn get_h54100s500ra_goal_current(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(38).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 550)?))
     }
     fn set_h54100s500ra_goal_current(
         &mut self,
         id: u8,
         params: u16,
     ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(38).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<2>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<4, 2>(id, 550, &params.to_le_bytes())
     }
     /// [Goal Velocity](#goal-velocity) (initial: -)
     fn get_h54100s500ra_goal_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(40).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 552)?))
     }
     fn set_h54100s500ra_goal_velocity(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(40).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 552, &params.to_le_bytes())
     }
     /// [Profile Acceleration](#profile-acceleration) (initial: -)
     fn get_h54100s500ra_profile_acceleration(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(44).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 556)?))
     }
     fn set_h54100s500ra_profile_acceleration(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(44).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 556, &params.to_le_bytes())
     }
     /// [Profile Velocity](#profile-velocity) (initial: -)
     fn get_h54100s500ra_profile_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(48).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 560)?))
     }
     fn set_h54100s500ra_profile_velocity(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(48).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 560, &params.to_le_bytes())
     }
     /// [Goal Position](#goal-position) (initial: -)
     fn get_h54100s500ra_goal_position(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(52).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 564)?))
     }
     fn set_h54100s500ra_goal_position(
         &mut self,
         id: u8,
         params: u32,
     ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
-        let mut content: Vec<u8, 6> = Vec::new();
-        content.push(52).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<6>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<4>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<6, 4>(id, 564, &params.to_le_bytes())
     }
     /// [Realtime Tick](#realtime-tick) (initial: -)
     fn get_h54100s500ra_realtime_tick(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(56).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 568)?))
     }
     /// [Moving](#moving) (initial: -)
     fn get_h54100s500ra_moving(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(58).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 570)?))
     }
     /// [Moving Status](#moving-status) (initial: -)
     fn get_h54100s500ra_moving_status(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(59).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 571)?))
     }
     /// [Present PWM](#present-pwm) (initial: -)
     fn get_h54100s500ra_present_pwm(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(60).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 572)?))
     }
     /// [Present Current](#present-current) (initial: -)
     fn get_h54100s500ra_present_current(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(62).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 574)?))
     }
     /// [Present Velocity](#present-velocity) (initial: -)
     fn get_h54100s500ra_present_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(64).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 576)?))
     }
     /// [Present Position](#present-position) (initial: -)
     fn get_h54100s500ra_present_position(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(68).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 580)?))
     }
     /// [Velocity Trajectory](#velocity-trajectory) (initial: -)
     fn get_h54100s500ra_velocity_trajectory(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(72).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 584)?))
     }
     /// [Position Trajectory](#position-trajectory) (initial: -)
     fn get_h54100s500ra_position_trajectory(&mut self, id: u8) -> Result<u32, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(76).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(4).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<4>()?.params;
-        Ok(u32::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u32::from_le_bytes(self.read(id, 588)?))
     }
     /// [Present Input Voltage](#present-input-voltage) (initial: -)
     fn get_h54100s500ra_present_input_voltage(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(80).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 592)?))
     }
     /// [Present Temperature](#present-temperature) (initial: -)
     fn get_h54100s500ra_present_temperature(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(82).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 594)?))
     }
     /// [External Port Data 1](#external-port-data) (initial: 0)
     fn get_h54100s500ra_external_port_data_1(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(88).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 600)?))
     }
     /// [External Port Data 2](#external-port-data) (initial: 0)
     fn get_h54100s500ra_external_port_data_2(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(90).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 602)?))
     }
     /// [External Port Data 3](#external-port-data) (initial: 0)
     fn get_h54100s500ra_external_port_data_3(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(92).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 604)?))
     }
     /// [External Port Data 4](#external-port-data) (initial: 0)
     fn get_h54100s500ra_external_port_data_4(&mut self, id: u8) -> Result<u16, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(94).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(2).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<2>()?.params;
-        Ok(u16::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u16::from_le_bytes(self.read(id, 606)?))
     }
     /// [Indirect Data 1](#indirect-data) (initial: 0)
     fn get_h54100s500ra_indirect_data_1(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(122).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 634)?))
     }
     fn set_h54100s500ra_indirect_data_1(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(122).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 634, &params.to_le_bytes())
     }
     /// [Indirect Data 2](#indirect-data) (initial: 0)
     fn get_h54100s500ra_indirect_data_2(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(123).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 635)?))
     }
     fn set_h54100s500ra_indirect_data_2(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(123).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 635, &params.to_le_bytes())
     }
     /// [Indirect Data 3](#indirect-data) (initial: 0)
     fn get_h54100s500ra_indirect_data_3(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(124).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 636)?))
     }
     fn set_h54100s500ra_indirect_data_3(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(124).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 636, &params.to_le_bytes())
     }
     /// [Indirect Data 128](#indirect-data) (initial: 0)
     fn get_h54100s500ra_indirect_data_128(&mut self, id: u8) -> Result<u8, Error<Serial>> {
-        let mut content: Vec<u8, 4> = Vec::new();
-        content.push(249).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        content.push(1).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(0).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Read, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<4>()?;
-        }
-        let params = self.recv::<1>()?.params;
-        Ok(u8::from_le_bytes(
-            params.into_array().map_err(|_| Error::TooSmall)?,
-        ))
+        Ok(u8::from_le_bytes(self.read(id, 761)?))
     }
     fn set_h54100s500ra_indirect_data_128(
         &mut self,
         id: u8,
         params: u8,
     ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
-        let mut content: Vec<u8, 3> = Vec::new();
-        content.push(249).map_err(|_| Error::TooSmall)?;
-        if PROTOCOL_VERSION == 2 {
-            content.push(2).map_err(|_| Error::TooSmall)?;
-        }
-        for byte in params.to_le_bytes() {
-            content.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        self.send(id, Instruction::Write, content)?;
-        if self.n_recv() == 2 {
-            self.recv::<3>()?;
-        }
-        if self.n_recv() >= 1 {
-            Ok(Some(self.recv::<1>()?))
-        } else {
-            Ok(None)
-        }
+        self.write::<3, 1>(id, 761, &params.to_le_bytes())
+    }
+    /// [Indirect Address N](#indirect-address), for N in 1..=128
+    fn get_h54100s500ra_indirect_address(&mut self, id: u8, n: u16) -> Result<u16, Error<Serial>> {
+        let address = match n {
+            1..=128 => 168 + 2 * (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        Ok(u16::from_le_bytes(self.read(id, address)?))
+    }
+    fn set_h54100s500ra_indirect_address(
+        &mut self,
+        id: u8,
+        n: u16,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        let address = match n {
+            1..=128 => 168 + 2 * (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        self.write::<4, 2>(id, address, &params.to_le_bytes())
+    }
+    /// [Indirect Data N](#indirect-data), for N in 1..=128
+    fn get_h54100s500ra_indirect_data(&mut self, id: u8, n: u16) -> Result<u8, Error<Serial>> {
+        let address = match n {
+            1..=128 => 634 + (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        Ok(u8::from_le_bytes(self.read(id, address)?))
+    }
+    fn set_h54100s500ra_indirect_data(
+        &mut self,
+        id: u8,
+        n: u16,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        let address = match n {
+            1..=128 => 634 + (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        self.write::<3, 1>(id, address, &params.to_le_bytes())
     }
 }
 
-impl<Serial, Direction> H54100S500RA<Serial, 1> for Controller<Serial, Direction, 1>
+impl<Serial, Direction, Delay> H54100S500RA<Serial, 1> for Controller<Serial, Direction, 1, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
     Direction: OutputPin,
+    Delay: DelayNs,
 {
 }
 
-impl<Serial, Direction> H54100S500RA<Serial, 2> for Controller<Serial, Direction, 2>
+impl<Serial, Direction, Delay> H54100S500RA<Serial, 2> for Controller<Serial, Direction, 2, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
     Direction: OutputPin,
+    Delay: DelayNs,
 {
 }
+
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait H54100S500RAAsync<Serial, const PROTOCOL_VERSION: u8>:
+    crate::protocol_async::AsyncProtocol<Serial, PROTOCOL_VERSION>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+{
+    /// [Model Number](#model-number) (initial: 53,769)
+    async fn get_h54100s500ra_model_number(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 0).await?))
+    }
+    /// [Model Information](#model-information) (initial: -)
+    async fn get_h54100s500ra_model_information(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 2).await?))
+    }
+    /// [Firmware Version](#firmware-version) (initial: -)
+    async fn get_h54100s500ra_firmware_version(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 6).await?))
+    }
+    /// [ID](#id) (initial: 1)
+    async fn get_h54100s500ra_id(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 7).await?))
+    }
+    async fn set_h54100s500ra_id(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 7, &params.to_le_bytes()).await
+    }
+    /// [Baud Rate](#baud-rate) (initial: 1)
+    async fn get_h54100s500ra_baud_rate(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 8).await?))
+    }
+    async fn set_h54100s500ra_baud_rate(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 8, &params.to_le_bytes()).await
+    }
+    /// [Return Delay Time](#return-delay-time) (initial: 250)
+    async fn get_h54100s500ra_return_delay_time(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 9).await?))
+    }
+    async fn set_h54100s500ra_return_delay_time(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 9, &params.to_le_bytes()).await
+    }
+    /// [Drive Mode](#drive-mode) (initial: 0)
+    async fn get_h54100s500ra_drive_mode(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 10).await?))
+    }
+    async fn set_h54100s500ra_drive_mode(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 10, &params.to_le_bytes()).await
+    }
+    /// [Operating Mode](#operating-mode) (initial: 3)
+    async fn get_h54100s500ra_operating_mode(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 11).await?))
+    }
+    async fn set_h54100s500ra_operating_mode(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 11, &params.to_le_bytes()).await
+    }
+    /// [Secondary ID](#secondary-id) (initial: 255)
+    async fn get_h54100s500ra_secondary_id(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 12).await?))
+    }
+    async fn set_h54100s500ra_secondary_id(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 12, &params.to_le_bytes()).await
+    }
+    /// [Homing Offset](#homing-offset) (initial: 0)
+    async fn get_h54100s500ra_homing_offset(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 20).await?))
+    }
+    async fn set_h54100s500ra_homing_offset(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 20, &params.to_le_bytes()).await
+    }
+    /// [Moving Threshold](#moving-threshold) (initial: 50)
+    async fn get_h54100s500ra_moving_threshold(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 24).await?))
+    }
+    async fn set_h54100s500ra_moving_threshold(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 24, &params.to_le_bytes()).await
+    }
+    /// [Temperature Limit](#temperature-limit) (initial: 80)
+    async fn get_h54100s500ra_temperature_limit(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 31).await?))
+    }
+    async fn set_h54100s500ra_temperature_limit(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 31, &params.to_le_bytes()).await
+    }
+    /// [Max Voltage Limit](#max-voltage-limit) (initial: 350)
+    async fn get_h54100s500ra_max_voltage_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 32).await?))
+    }
+    async fn set_h54100s500ra_max_voltage_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 32, &params.to_le_bytes()).await
+    }
+    /// [Min Voltage Limit](#min-voltage-limit) (initial: 150)
+    async fn get_h54100s500ra_min_voltage_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 34).await?))
+    }
+    async fn set_h54100s500ra_min_voltage_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 34, &params.to_le_bytes()).await
+    }
+    /// [PWM Limit](#pwm-limit) (initial: 2,009)
+    async fn get_h54100s500ra_pwm_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 36).await?))
+    }
+    async fn set_h54100s500ra_pwm_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 36, &params.to_le_bytes()).await
+    }
+    /// [Current Limit](#current-limit) (initial: 15,900)
+    async fn get_h54100s500ra_current_limit(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 38).await?))
+    }
+    async fn set_h54100s500ra_current_limit(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 38, &params.to_le_bytes()).await
+    }
+    /// [Acceleration Limit](#acceleration-limit) (initial: 10,639)
+    async fn get_h54100s500ra_acceleration_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 40).await?))
+    }
+    async fn set_h54100s500ra_acceleration_limit(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 40, &params.to_le_bytes()).await
+    }
+    /// [Velocity Limit](#velocity-limit) (initial: 2,920)
+    async fn get_h54100s500ra_velocity_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 44).await?))
+    }
+    async fn set_h54100s500ra_velocity_limit(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 44, &params.to_le_bytes()).await
+    }
+    /// [Max Position Limit](#max-position-limit) (initial: 501,433)
+    async fn get_h54100s500ra_max_position_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 48).await?))
+    }
+    async fn set_h54100s500ra_max_position_limit(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 48, &params.to_le_bytes()).await
+    }
+    /// [Min Position Limit](#min-position-limit) (initial: -501,433)
+    async fn get_h54100s500ra_min_position_limit(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 52).await?))
+    }
+    async fn set_h54100s500ra_min_position_limit(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 52, &params.to_le_bytes()).await
+    }
+    /// [External Port Mode 1](#external-port-mode) (initial: 3)
+    async fn get_h54100s500ra_external_port_mode_1(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 56).await?))
+    }
+    async fn set_h54100s500ra_external_port_mode_1(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 56, &params.to_le_bytes()).await
+    }
+    /// [External Port Mode 2](#external-port-mode) (initial: 3)
+    async fn get_h54100s500ra_external_port_mode_2(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 57).await?))
+    }
+    async fn set_h54100s500ra_external_port_mode_2(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 57, &params.to_le_bytes()).await
+    }
+    /// [External Port Mode 3](#external-port-mode) (initial: 3)
+    async fn get_h54100s500ra_external_port_mode_3(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 58).await?))
+    }
+    async fn set_h54100s500ra_external_port_mode_3(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 58, &params.to_le_bytes()).await
+    }
+    /// [External Port Mode 4](#external-port-mode) (initial: 3)
+    async fn get_h54100s500ra_external_port_mode_4(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 59).await?))
+    }
+    async fn set_h54100s500ra_external_port_mode_4(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 59, &params.to_le_bytes()).await
+    }
+    /// [Shutdown](#shutdown) (initial: 52)
+    async fn get_h54100s500ra_shutdown(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 63).await?))
+    }
+    async fn set_h54100s500ra_shutdown(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 63, &params.to_le_bytes()).await
+    }
+    /// [Indirect Address 1](#indirect-address) (initial: 634)
+    async fn get_h54100s500ra_indirect_address_1(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 168).await?))
+    }
+    async fn set_h54100s500ra_indirect_address_1(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 168, &params.to_le_bytes()).await
+    }
+    /// [Indirect Address 2](#indirect-address) (initial: 635)
+    async fn get_h54100s500ra_indirect_address_2(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 170).await?))
+    }
+    async fn set_h54100s500ra_indirect_address_2(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 170, &params.to_le_bytes()).await
+    }
+    /// [Indirect Address 3](#indirect-address) (initial: 636)
+    async fn get_h54100s500ra_indirect_address_3(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 172).await?))
+    }
+    async fn set_h54100s500ra_indirect_address_3(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 172, &params.to_le_bytes()).await
+    }
+    /// [Indirect Address 128](#indirect-address) (initial: 761)
+    async fn get_h54100s500ra_indirect_address_128(
+        &mut self,
+        id: u8,
+    ) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 422).await?))
+    }
+    async fn set_h54100s500ra_indirect_address_128(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 422, &params.to_le_bytes()).await
+    }
+    /// [Torque Enable](#torque-enable) (initial: 0)
+    async fn get_h54100s500ra_torque_enable(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 512).await?))
+    }
+    async fn set_h54100s500ra_torque_enable(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 512, &params.to_le_bytes()).await
+    }
+    /// [LED Red](#led) (initial: 0)
+    async fn get_h54100s500ra_led_red(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 513).await?))
+    }
+    async fn set_h54100s500ra_led_red(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 513, &params.to_le_bytes()).await
+    }
+    /// [LED Green](#led) (initial: 0)
+    async fn get_h54100s500ra_led_green(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 514).await?))
+    }
+    async fn set_h54100s500ra_led_green(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 514, &params.to_le_bytes()).await
+    }
+    /// [LED Blue](#led) (initial: 0)
+    async fn get_h54100s500ra_led_blue(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 515).await?))
+    }
+    async fn set_h54100s500ra_led_blue(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 515, &params.to_le_bytes()).await
+    }
+    /// [Status Return Level](#status-return-level) (initial: 2)
+    async fn get_h54100s500ra_status_return_level(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        let level = u8::from_le_bytes(self.read(id, 516).await?);
+        self.set_status_return_level(id, level.into());
+        Ok(level)
+    }
+    async fn set_h54100s500ra_status_return_level(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        let status = self.write::<3, 1>(id, 516, &params.to_le_bytes()).await?;
+        self.set_status_return_level(id, params.into());
+        Ok(status)
+    }
+    /// [Registered Instruction](#registered-instruction) (initial: 0)
+    async fn get_h54100s500ra_registered_instruction(
+        &mut self,
+        id: u8,
+    ) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 517).await?))
+    }
+    /// [Hardware Error Status](#hardware-error-status) (initial: 0)
+    async fn get_h54100s500ra_hardware_error_status(
+        &mut self,
+        id: u8,
+    ) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 518).await?))
+    }
+    /// [Velocity I Gain](#velocity-i-gain) (initial: -)
+    async fn get_h54100s500ra_velocity_i_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 524).await?))
+    }
+    async fn set_h54100s500ra_velocity_i_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 524, &params.to_le_bytes()).await
+    }
+    /// [Velocity P Gain](#velocity-p-gain) (initial: -)
+    async fn get_h54100s500ra_velocity_p_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 526).await?))
+    }
+    async fn set_h54100s500ra_velocity_p_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 526, &params.to_le_bytes()).await
+    }
+    /// [Position D Gain](#position-p-gain) (initial: -)
+    async fn get_h54100s500ra_position_d_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 528).await?))
+    }
+    async fn set_h54100s500ra_position_d_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 528, &params.to_le_bytes()).await
+    }
+    /// [Position P Gain](#position-p-gain) (initial: -)
+    async fn get_h54100s500ra_position_p_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 532).await?))
+    }
+    async fn set_h54100s500ra_position_p_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 532, &params.to_le_bytes()).await
+    }
+    /// [Position I Gain](#position-p-gain) (initial: -)
+    async fn get_h54100s500ra_position_i_gain(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 530).await?))
+    }
+    async fn set_h54100s500ra_position_i_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 530, &params.to_le_bytes()).await
+    }
+    /// [Feedforward 2nd Gain](#feedforward-2nd-gain) (initial: -)
+    async fn get_h54100s500ra_feedforward_2nd_gain(
+        &mut self,
+        id: u8,
+    ) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 536).await?))
+    }
+    async fn set_h54100s500ra_feedforward_2nd_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 536, &params.to_le_bytes()).await
+    }
+    /// [Feedforward 1st Gain](#feedforward-1st-gain) (initial: -)
+    async fn get_h54100s500ra_feedforward_1st_gain(
+        &mut self,
+        id: u8,
+    ) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 538).await?))
+    }
+    async fn set_h54100s500ra_feedforward_1st_gain(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 538, &params.to_le_bytes()).await
+    }
+    /// [Bus Watchdog](#bus-watchdog) (initial: -)
+    async fn get_h54100s500ra_bus_watchdog(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 546).await?))
+    }
+    async fn set_h54100s500ra_bus_watchdog(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 546, &params.to_le_bytes()).await
+    }
+    /// [Goal PWM](#goal-pwm) (initial: -)
+    async fn get_h54100s500ra_goal_pwm(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 548).await?))
+    }
+    async fn set_h54100s500ra_goal_pwm(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 548, &params.to_le_bytes()).await
+    }
+    /// [Goal Current](#goal-current) (initial: -)
+    async fn get_h54100s500ra_goal_current(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 550).await?))
+    }
+    async fn set_h54100s500ra_goal_current(
+        &mut self,
+        id: u8,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        self.write::<4, 2>(id, 550, &params.to_le_bytes()).await
+    }
+    /// [Goal Velocity](#goal-velocity) (initial: -)
+    async fn get_h54100s500ra_goal_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 552).await?))
+    }
+    async fn set_h54100s500ra_goal_velocity(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 552, &params.to_le_bytes()).await
+    }
+    /// [Profile Acceleration](#profile-acceleration) (initial: -)
+    async fn get_h54100s500ra_profile_acceleration(
+        &mut self,
+        id: u8,
+    ) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 556).await?))
+    }
+    async fn set_h54100s500ra_profile_acceleration(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 556, &params.to_le_bytes()).await
+    }
+    /// [Profile Velocity](#profile-velocity) (initial: -)
+    async fn get_h54100s500ra_profile_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 560).await?))
+    }
+    async fn set_h54100s500ra_profile_velocity(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 560, &params.to_le_bytes()).await
+    }
+    /// [Goal Position](#goal-position) (initial: -)
+    async fn get_h54100s500ra_goal_position(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 564).await?))
+    }
+    async fn set_h54100s500ra_goal_position(
+        &mut self,
+        id: u8,
+        params: u32,
+    ) -> Result<Option<StatusPacket<4>>, Error<Serial>> {
+        self.write::<6, 4>(id, 564, &params.to_le_bytes()).await
+    }
+    /// [Realtime Tick](#realtime-tick) (initial: -)
+    async fn get_h54100s500ra_realtime_tick(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 568).await?))
+    }
+    /// [Moving](#moving) (initial: -)
+    async fn get_h54100s500ra_moving(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 570).await?))
+    }
+    /// [Moving Status](#moving-status) (initial: -)
+    async fn get_h54100s500ra_moving_status(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 571).await?))
+    }
+    /// [Present PWM](#present-pwm) (initial: -)
+    async fn get_h54100s500ra_present_pwm(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 572).await?))
+    }
+    /// [Present Current](#present-current) (initial: -)
+    async fn get_h54100s500ra_present_current(&mut self, id: u8) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 574).await?))
+    }
+    /// [Present Velocity](#present-velocity) (initial: -)
+    async fn get_h54100s500ra_present_velocity(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 576).await?))
+    }
+    /// [Present Position](#present-position) (initial: -)
+    async fn get_h54100s500ra_present_position(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 580).await?))
+    }
+    /// [Velocity Trajectory](#velocity-trajectory) (initial: -)
+    async fn get_h54100s500ra_velocity_trajectory(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 584).await?))
+    }
+    /// [Position Trajectory](#position-trajectory) (initial: -)
+    async fn get_h54100s500ra_position_trajectory(&mut self, id: u8) -> Result<u32, Error<Serial>> {
+        Ok(u32::from_le_bytes(self.read(id, 588).await?))
+    }
+    /// [Present Input Voltage](#present-input-voltage) (initial: -)
+    async fn get_h54100s500ra_present_input_voltage(
+        &mut self,
+        id: u8,
+    ) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 592).await?))
+    }
+    /// [Present Temperature](#present-temperature) (initial: -)
+    async fn get_h54100s500ra_present_temperature(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 594).await?))
+    }
+    /// [External Port Data 1](#external-port-data) (initial: 0)
+    async fn get_h54100s500ra_external_port_data_1(
+        &mut self,
+        id: u8,
+    ) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 600).await?))
+    }
+    /// [External Port Data 2](#external-port-data) (initial: 0)
+    async fn get_h54100s500ra_external_port_data_2(
+        &mut self,
+        id: u8,
+    ) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 602).await?))
+    }
+    /// [External Port Data 3](#external-port-data) (initial: 0)
+    async fn get_h54100s500ra_external_port_data_3(
+        &mut self,
+        id: u8,
+    ) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 604).await?))
+    }
+    /// [External Port Data 4](#external-port-data) (initial: 0)
+    async fn get_h54100s500ra_external_port_data_4(
+        &mut self,
+        id: u8,
+    ) -> Result<u16, Error<Serial>> {
+        Ok(u16::from_le_bytes(self.read(id, 606).await?))
+    }
+    /// [Indirect Data 1](#indirect-data) (initial: 0)
+    async fn get_h54100s500ra_indirect_data_1(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 634).await?))
+    }
+    async fn set_h54100s500ra_indirect_data_1(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 634, &params.to_le_bytes()).await
+    }
+    /// [Indirect Data 2](#indirect-data) (initial: 0)
+    async fn get_h54100s500ra_indirect_data_2(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 635).await?))
+    }
+    async fn set_h54100s500ra_indirect_data_2(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 635, &params.to_le_bytes()).await
+    }
+    /// [Indirect Data 3](#indirect-data) (initial: 0)
+    async fn get_h54100s500ra_indirect_data_3(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 636).await?))
+    }
+    async fn set_h54100s500ra_indirect_data_3(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 636, &params.to_le_bytes()).await
+    }
+    /// [Indirect Data 128](#indirect-data) (initial: 0)
+    async fn get_h54100s500ra_indirect_data_128(&mut self, id: u8) -> Result<u8, Error<Serial>> {
+        Ok(u8::from_le_bytes(self.read(id, 761).await?))
+    }
+    async fn set_h54100s500ra_indirect_data_128(
+        &mut self,
+        id: u8,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        self.write::<3, 1>(id, 761, &params.to_le_bytes()).await
+    }
+    /// [Indirect Address N](#indirect-address), for N in 1..=128
+    async fn get_h54100s500ra_indirect_address(
+        &mut self,
+        id: u8,
+        n: u16,
+    ) -> Result<u16, Error<Serial>> {
+        let address = match n {
+            1..=128 => 168 + 2 * (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        Ok(u16::from_le_bytes(self.read(id, address).await?))
+    }
+    async fn set_h54100s500ra_indirect_address(
+        &mut self,
+        id: u8,
+        n: u16,
+        params: u16,
+    ) -> Result<Option<StatusPacket<2>>, Error<Serial>> {
+        let address = match n {
+            1..=128 => 168 + 2 * (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        self.write::<4, 2>(id, address, &params.to_le_bytes()).await
+    }
+    /// [Indirect Data N](#indirect-data), for N in 1..=128
+    async fn get_h54100s500ra_indirect_data(
+        &mut self,
+        id: u8,
+        n: u16,
+    ) -> Result<u8, Error<Serial>> {
+        let address = match n {
+            1..=128 => 634 + (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        Ok(u8::from_le_bytes(self.read(id, address).await?))
+    }
+    async fn set_h54100s500ra_indirect_data(
+        &mut self,
+        id: u8,
+        n: u16,
+        params: u8,
+    ) -> Result<Option<StatusPacket<1>>, Error<Serial>> {
+        let address = match n {
+            1..=128 => 634 + (n - 1),
+            _ => return Err(Error::UnknownRegister),
+        };
+        self.write::<3, 1>(id, address, &params.to_le_bytes()).await
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Serial, Direction, Delay> H54100S500RAAsync<Serial, 1>
+    for Controller<Serial, Direction, 1, Delay>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+    Direction: OutputPin,
+    Delay: embedded_hal_async::delay::DelayNs,
+{
+}
+
+#[cfg(feature = "async")]
+impl<Serial, Direction, Delay> H54100S500RAAsync<Serial, 2>
+    for Controller<Serial, Direction, 2, Delay>
+where
+    Serial: embedded_io_async::Read + embedded_io_async::Write,
+    Direction: OutputPin,
+    Delay: embedded_hal_async::delay::DelayNs,
+{
+}
+
+pub const MODEL_NUMBER: u16 = 53769;
+pub const CONTROL_TABLE: &[Register] = &[
+    Register {
+        name: "model_number",
+        address: 0,
+        size: 2,
+        writable: false,
+        description: "[Model Number](#model-number)",
+        initial: "53,769",
+    },
+    Register {
+        name: "model_information",
+        address: 2,
+        size: 4,
+        writable: false,
+        description: "[Model Information](#model-information)",
+        initial: "-",
+    },
+    Register {
+        name: "firmware_version",
+        address: 6,
+        size: 1,
+        writable: false,
+        description: "[Firmware Version](#firmware-version)",
+        initial: "-",
+    },
+    Register {
+        name: "id",
+        address: 7,
+        size: 1,
+        writable: true,
+        description: "[ID](#id)",
+        initial: "1",
+    },
+    Register {
+        name: "baud_rate",
+        address: 8,
+        size: 1,
+        writable: true,
+        description: "[Baud Rate](#baud-rate)",
+        initial: "1",
+    },
+    Register {
+        name: "return_delay_time",
+        address: 9,
+        size: 1,
+        writable: true,
+        description: "[Return Delay Time](#return-delay-time)",
+        initial: "250",
+    },
+    Register {
+        name: "drive_mode",
+        address: 10,
+        size: 1,
+        writable: true,
+        description: "[Drive Mode](#drive-mode)",
+        initial: "0",
+    },
+    Register {
+        name: "operating_mode",
+        address: 11,
+        size: 1,
+        writable: true,
+        description: "[Operating Mode](#operating-mode)",
+        initial: "3",
+    },
+    Register {
+        name: "secondary_id",
+        address: 12,
+        size: 1,
+        writable: true,
+        description: "[Secondary ID](#secondary-id)",
+        initial: "255",
+    },
+    Register {
+        name: "homing_offset",
+        address: 20,
+        size: 4,
+        writable: true,
+        description: "[Homing Offset](#homing-offset)",
+        initial: "0",
+    },
+    Register {
+        name: "moving_threshold",
+        address: 24,
+        size: 4,
+        writable: true,
+        description: "[Moving Threshold](#moving-threshold)",
+        initial: "50",
+    },
+    Register {
+        name: "temperature_limit",
+        address: 31,
+        size: 1,
+        writable: true,
+        description: "[Temperature Limit](#temperature-limit)",
+        initial: "80",
+    },
+    Register {
+        name: "max_voltage_limit",
+        address: 32,
+        size: 2,
+        writable: true,
+        description: "[Max Voltage Limit](#max-voltage-limit)",
+        initial: "350",
+    },
+    Register {
+        name: "min_voltage_limit",
+        address: 34,
+        size: 2,
+        writable: true,
+        description: "[Min Voltage Limit](#min-voltage-limit)",
+        initial: "150",
+    },
+    Register {
+        name: "pwm_limit",
+        address: 36,
+        size: 2,
+        writable: true,
+        description: "[PWM Limit](#pwm-limit)",
+        initial: "2,009",
+    },
+    Register {
+        name: "current_limit",
+        address: 38,
+        size: 2,
+        writable: true,
+        description: "[Current Limit](#current-limit)",
+        initial: "15,900",
+    },
+    Register {
+        name: "acceleration_limit",
+        address: 40,
+        size: 4,
+        writable: true,
+        description: "[Acceleration Limit](#acceleration-limit)",
+        initial: "10,639",
+    },
+    Register {
+        name: "velocity_limit",
+        address: 44,
+        size: 4,
+        writable: true,
+        description: "[Velocity Limit](#velocity-limit)",
+        initial: "2,920",
+    },
+    Register {
+        name: "max_position_limit",
+        address: 48,
+        size: 4,
+        writable: true,
+        description: "[Max Position Limit](#max-position-limit)",
+        initial: "501,433",
+    },
+    Register {
+        name: "min_position_limit",
+        address: 52,
+        size: 4,
+        writable: true,
+        description: "[Min Position Limit](#min-position-limit)",
+        initial: "-501,433",
+    },
+    Register {
+        name: "external_port_mode_1",
+        address: 56,
+        size: 1,
+        writable: true,
+        description: "[External Port Mode 1](#external-port-mode)",
+        initial: "3",
+    },
+    Register {
+        name: "external_port_mode_2",
+        address: 57,
+        size: 1,
+        writable: true,
+        description: "[External Port Mode 2](#external-port-mode)",
+        initial: "3",
+    },
+    Register {
+        name: "external_port_mode_3",
+        address: 58,
+        size: 1,
+        writable: true,
+        description: "[External Port Mode 3](#external-port-mode)",
+        initial: "3",
+    },
+    Register {
+        name: "external_port_mode_4",
+        address: 59,
+        size: 1,
+        writable: true,
+        description: "[External Port Mode 4](#external-port-mode)",
+        initial: "3",
+    },
+    Register {
+        name: "shutdown",
+        address: 63,
+        size: 1,
+        writable: true,
+        description: "[Shutdown](#shutdown)",
+        initial: "52",
+    },
+    Register {
+        name: "indirect_address_1",
+        address: 168,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 1](#indirect-address)",
+        initial: "634",
+    },
+    Register {
+        name: "indirect_address_2",
+        address: 170,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 2](#indirect-address)",
+        initial: "635",
+    },
+    Register {
+        name: "indirect_address_3",
+        address: 172,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 3](#indirect-address)",
+        initial: "636",
+    },
+    Register {
+        name: "indirect_address_4",
+        address: 174,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 4](#indirect-address)",
+        initial: "637",
+    },
+    Register {
+        name: "indirect_address_5",
+        address: 176,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 5](#indirect-address)",
+        initial: "638",
+    },
+    Register {
+        name: "indirect_address_6",
+        address: 178,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 6](#indirect-address)",
+        initial: "639",
+    },
+    Register {
+        name: "indirect_address_7",
+        address: 180,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 7](#indirect-address)",
+        initial: "640",
+    },
+    Register {
+        name: "indirect_address_8",
+        address: 182,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 8](#indirect-address)",
+        initial: "641",
+    },
+    Register {
+        name: "indirect_address_9",
+        address: 184,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 9](#indirect-address)",
+        initial: "642",
+    },
+    Register {
+        name: "indirect_address_10",
+        address: 186,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 10](#indirect-address)",
+        initial: "643",
+    },
+    Register {
+        name: "indirect_address_11",
+        address: 188,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 11](#indirect-address)",
+        initial: "644",
+    },
+    Register {
+        name: "indirect_address_12",
+        address: 190,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 12](#indirect-address)",
+        initial: "645",
+    },
+    Register {
+        name: "indirect_address_13",
+        address: 192,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 13](#indirect-address)",
+        initial: "646",
+    },
+    Register {
+        name: "indirect_address_14",
+        address: 194,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 14](#indirect-address)",
+        initial: "647",
+    },
+    Register {
+        name: "indirect_address_15",
+        address: 196,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 15](#indirect-address)",
+        initial: "648",
+    },
+    Register {
+        name: "indirect_address_16",
+        address: 198,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 16](#indirect-address)",
+        initial: "649",
+    },
+    Register {
+        name: "indirect_address_17",
+        address: 200,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 17](#indirect-address)",
+        initial: "650",
+    },
+    Register {
+        name: "indirect_address_18",
+        address: 202,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 18](#indirect-address)",
+        initial: "651",
+    },
+    Register {
+        name: "indirect_address_19",
+        address: 204,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 19](#indirect-address)",
+        initial: "652",
+    },
+    Register {
+        name: "indirect_address_20",
+        address: 206,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 20](#indirect-address)",
+        initial: "653",
+    },
+    Register {
+        name: "indirect_address_21",
+        address: 208,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 21](#indirect-address)",
+        initial: "654",
+    },
+    Register {
+        name: "indirect_address_22",
+        address: 210,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 22](#indirect-address)",
+        initial: "655",
+    },
+    Register {
+        name: "indirect_address_23",
+        address: 212,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 23](#indirect-address)",
+        initial: "656",
+    },
+    Register {
+        name: "indirect_address_24",
+        address: 214,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 24](#indirect-address)",
+        initial: "657",
+    },
+    Register {
+        name: "indirect_address_25",
+        address: 216,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 25](#indirect-address)",
+        initial: "658",
+    },
+    Register {
+        name: "indirect_address_26",
+        address: 218,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 26](#indirect-address)",
+        initial: "659",
+    },
+    Register {
+        name: "indirect_address_27",
+        address: 220,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 27](#indirect-address)",
+        initial: "660",
+    },
+    Register {
+        name: "indirect_address_28",
+        address: 222,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 28](#indirect-address)",
+        initial: "661",
+    },
+    Register {
+        name: "indirect_address_29",
+        address: 224,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 29](#indirect-address)",
+        initial: "662",
+    },
+    Register {
+        name: "indirect_address_30",
+        address: 226,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 30](#indirect-address)",
+        initial: "663",
+    },
+    Register {
+        name: "indirect_address_31",
+        address: 228,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 31](#indirect-address)",
+        initial: "664",
+    },
+    Register {
+        name: "indirect_address_32",
+        address: 230,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 32](#indirect-address)",
+        initial: "665",
+    },
+    Register {
+        name: "indirect_address_33",
+        address: 232,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 33](#indirect-address)",
+        initial: "666",
+    },
+    Register {
+        name: "indirect_address_34",
+        address: 234,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 34](#indirect-address)",
+        initial: "667",
+    },
+    Register {
+        name: "indirect_address_35",
+        address: 236,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 35](#indirect-address)",
+        initial: "668",
+    },
+    Register {
+        name: "indirect_address_36",
+        address: 238,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 36](#indirect-address)",
+        initial: "669",
+    },
+    Register {
+        name: "indirect_address_37",
+        address: 240,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 37](#indirect-address)",
+        initial: "670",
+    },
+    Register {
+        name: "indirect_address_38",
+        address: 242,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 38](#indirect-address)",
+        initial: "671",
+    },
+    Register {
+        name: "indirect_address_39",
+        address: 244,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 39](#indirect-address)",
+        initial: "672",
+    },
+    Register {
+        name: "indirect_address_40",
+        address: 246,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 40](#indirect-address)",
+        initial: "673",
+    },
+    Register {
+        name: "indirect_address_41",
+        address: 248,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 41](#indirect-address)",
+        initial: "674",
+    },
+    Register {
+        name: "indirect_address_42",
+        address: 250,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 42](#indirect-address)",
+        initial: "675",
+    },
+    Register {
+        name: "indirect_address_43",
+        address: 252,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 43](#indirect-address)",
+        initial: "676",
+    },
+    Register {
+        name: "indirect_address_44",
+        address: 254,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 44](#indirect-address)",
+        initial: "677",
+    },
+    Register {
+        name: "indirect_address_45",
+        address: 256,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 45](#indirect-address)",
+        initial: "678",
+    },
+    Register {
+        name: "indirect_address_46",
+        address: 258,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 46](#indirect-address)",
+        initial: "679",
+    },
+    Register {
+        name: "indirect_address_47",
+        address: 260,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 47](#indirect-address)",
+        initial: "680",
+    },
+    Register {
+        name: "indirect_address_48",
+        address: 262,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 48](#indirect-address)",
+        initial: "681",
+    },
+    Register {
+        name: "indirect_address_49",
+        address: 264,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 49](#indirect-address)",
+        initial: "682",
+    },
+    Register {
+        name: "indirect_address_50",
+        address: 266,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 50](#indirect-address)",
+        initial: "683",
+    },
+    Register {
+        name: "indirect_address_51",
+        address: 268,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 51](#indirect-address)",
+        initial: "684",
+    },
+    Register {
+        name: "indirect_address_52",
+        address: 270,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 52](#indirect-address)",
+        initial: "685",
+    },
+    Register {
+        name: "indirect_address_53",
+        address: 272,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 53](#indirect-address)",
+        initial: "686",
+    },
+    Register {
+        name: "indirect_address_54",
+        address: 274,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 54](#indirect-address)",
+        initial: "687",
+    },
+    Register {
+        name: "indirect_address_55",
+        address: 276,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 55](#indirect-address)",
+        initial: "688",
+    },
+    Register {
+        name: "indirect_address_56",
+        address: 278,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 56](#indirect-address)",
+        initial: "689",
+    },
+    Register {
+        name: "indirect_address_57",
+        address: 280,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 57](#indirect-address)",
+        initial: "690",
+    },
+    Register {
+        name: "indirect_address_58",
+        address: 282,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 58](#indirect-address)",
+        initial: "691",
+    },
+    Register {
+        name: "indirect_address_59",
+        address: 284,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 59](#indirect-address)",
+        initial: "692",
+    },
+    Register {
+        name: "indirect_address_60",
+        address: 286,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 60](#indirect-address)",
+        initial: "693",
+    },
+    Register {
+        name: "indirect_address_61",
+        address: 288,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 61](#indirect-address)",
+        initial: "694",
+    },
+    Register {
+        name: "indirect_address_62",
+        address: 290,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 62](#indirect-address)",
+        initial: "695",
+    },
+    Register {
+        name: "indirect_address_63",
+        address: 292,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 63](#indirect-address)",
+        initial: "696",
+    },
+    Register {
+        name: "indirect_address_64",
+        address: 294,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 64](#indirect-address)",
+        initial: "697",
+    },
+    Register {
+        name: "indirect_address_65",
+        address: 296,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 65](#indirect-address)",
+        initial: "698",
+    },
+    Register {
+        name: "indirect_address_66",
+        address: 298,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 66](#indirect-address)",
+        initial: "699",
+    },
+    Register {
+        name: "indirect_address_67",
+        address: 300,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 67](#indirect-address)",
+        initial: "700",
+    },
+    Register {
+        name: "indirect_address_68",
+        address: 302,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 68](#indirect-address)",
+        initial: "701",
+    },
+    Register {
+        name: "indirect_address_69",
+        address: 304,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 69](#indirect-address)",
+        initial: "702",
+    },
+    Register {
+        name: "indirect_address_70",
+        address: 306,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 70](#indirect-address)",
+        initial: "703",
+    },
+    Register {
+        name: "indirect_address_71",
+        address: 308,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 71](#indirect-address)",
+        initial: "704",
+    },
+    Register {
+        name: "indirect_address_72",
+        address: 310,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 72](#indirect-address)",
+        initial: "705",
+    },
+    Register {
+        name: "indirect_address_73",
+        address: 312,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 73](#indirect-address)",
+        initial: "706",
+    },
+    Register {
+        name: "indirect_address_74",
+        address: 314,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 74](#indirect-address)",
+        initial: "707",
+    },
+    Register {
+        name: "indirect_address_75",
+        address: 316,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 75](#indirect-address)",
+        initial: "708",
+    },
+    Register {
+        name: "indirect_address_76",
+        address: 318,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 76](#indirect-address)",
+        initial: "709",
+    },
+    Register {
+        name: "indirect_address_77",
+        address: 320,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 77](#indirect-address)",
+        initial: "710",
+    },
+    Register {
+        name: "indirect_address_78",
+        address: 322,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 78](#indirect-address)",
+        initial: "711",
+    },
+    Register {
+        name: "indirect_address_79",
+        address: 324,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 79](#indirect-address)",
+        initial: "712",
+    },
+    Register {
+        name: "indirect_address_80",
+        address: 326,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 80](#indirect-address)",
+        initial: "713",
+    },
+    Register {
+        name: "indirect_address_81",
+        address: 328,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 81](#indirect-address)",
+        initial: "714",
+    },
+    Register {
+        name: "indirect_address_82",
+        address: 330,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 82](#indirect-address)",
+        initial: "715",
+    },
+    Register {
+        name: "indirect_address_83",
+        address: 332,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 83](#indirect-address)",
+        initial: "716",
+    },
+    Register {
+        name: "indirect_address_84",
+        address: 334,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 84](#indirect-address)",
+        initial: "717",
+    },
+    Register {
+        name: "indirect_address_85",
+        address: 336,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 85](#indirect-address)",
+        initial: "718",
+    },
+    Register {
+        name: "indirect_address_86",
+        address: 338,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 86](#indirect-address)",
+        initial: "719",
+    },
+    Register {
+        name: "indirect_address_87",
+        address: 340,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 87](#indirect-address)",
+        initial: "720",
+    },
+    Register {
+        name: "indirect_address_88",
+        address: 342,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 88](#indirect-address)",
+        initial: "721",
+    },
+    Register {
+        name: "indirect_address_89",
+        address: 344,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 89](#indirect-address)",
+        initial: "722",
+    },
+    Register {
+        name: "indirect_address_90",
+        address: 346,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 90](#indirect-address)",
+        initial: "723",
+    },
+    Register {
+        name: "indirect_address_91",
+        address: 348,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 91](#indirect-address)",
+        initial: "724",
+    },
+    Register {
+        name: "indirect_address_92",
+        address: 350,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 92](#indirect-address)",
+        initial: "725",
+    },
+    Register {
+        name: "indirect_address_93",
+        address: 352,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 93](#indirect-address)",
+        initial: "726",
+    },
+    Register {
+        name: "indirect_address_94",
+        address: 354,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 94](#indirect-address)",
+        initial: "727",
+    },
+    Register {
+        name: "indirect_address_95",
+        address: 356,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 95](#indirect-address)",
+        initial: "728",
+    },
+    Register {
+        name: "indirect_address_96",
+        address: 358,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 96](#indirect-address)",
+        initial: "729",
+    },
+    Register {
+        name: "indirect_address_97",
+        address: 360,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 97](#indirect-address)",
+        initial: "730",
+    },
+    Register {
+        name: "indirect_address_98",
+        address: 362,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 98](#indirect-address)",
+        initial: "731",
+    },
+    Register {
+        name: "indirect_address_99",
+        address: 364,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 99](#indirect-address)",
+        initial: "732",
+    },
+    Register {
+        name: "indirect_address_100",
+        address: 366,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 100](#indirect-address)",
+        initial: "733",
+    },
+    Register {
+        name: "indirect_address_101",
+        address: 368,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 101](#indirect-address)",
+        initial: "734",
+    },
+    Register {
+        name: "indirect_address_102",
+        address: 370,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 102](#indirect-address)",
+        initial: "735",
+    },
+    Register {
+        name: "indirect_address_103",
+        address: 372,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 103](#indirect-address)",
+        initial: "736",
+    },
+    Register {
+        name: "indirect_address_104",
+        address: 374,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 104](#indirect-address)",
+        initial: "737",
+    },
+    Register {
+        name: "indirect_address_105",
+        address: 376,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 105](#indirect-address)",
+        initial: "738",
+    },
+    Register {
+        name: "indirect_address_106",
+        address: 378,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 106](#indirect-address)",
+        initial: "739",
+    },
+    Register {
+        name: "indirect_address_107",
+        address: 380,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 107](#indirect-address)",
+        initial: "740",
+    },
+    Register {
+        name: "indirect_address_108",
+        address: 382,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 108](#indirect-address)",
+        initial: "741",
+    },
+    Register {
+        name: "indirect_address_109",
+        address: 384,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 109](#indirect-address)",
+        initial: "742",
+    },
+    Register {
+        name: "indirect_address_110",
+        address: 386,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 110](#indirect-address)",
+        initial: "743",
+    },
+    Register {
+        name: "indirect_address_111",
+        address: 388,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 111](#indirect-address)",
+        initial: "744",
+    },
+    Register {
+        name: "indirect_address_112",
+        address: 390,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 112](#indirect-address)",
+        initial: "745",
+    },
+    Register {
+        name: "indirect_address_113",
+        address: 392,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 113](#indirect-address)",
+        initial: "746",
+    },
+    Register {
+        name: "indirect_address_114",
+        address: 394,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 114](#indirect-address)",
+        initial: "747",
+    },
+    Register {
+        name: "indirect_address_115",
+        address: 396,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 115](#indirect-address)",
+        initial: "748",
+    },
+    Register {
+        name: "indirect_address_116",
+        address: 398,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 116](#indirect-address)",
+        initial: "749",
+    },
+    Register {
+        name: "indirect_address_117",
+        address: 400,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 117](#indirect-address)",
+        initial: "750",
+    },
+    Register {
+        name: "indirect_address_118",
+        address: 402,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 118](#indirect-address)",
+        initial: "751",
+    },
+    Register {
+        name: "indirect_address_119",
+        address: 404,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 119](#indirect-address)",
+        initial: "752",
+    },
+    Register {
+        name: "indirect_address_120",
+        address: 406,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 120](#indirect-address)",
+        initial: "753",
+    },
+    Register {
+        name: "indirect_address_121",
+        address: 408,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 121](#indirect-address)",
+        initial: "754",
+    },
+    Register {
+        name: "indirect_address_122",
+        address: 410,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 122](#indirect-address)",
+        initial: "755",
+    },
+    Register {
+        name: "indirect_address_123",
+        address: 412,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 123](#indirect-address)",
+        initial: "756",
+    },
+    Register {
+        name: "indirect_address_124",
+        address: 414,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 124](#indirect-address)",
+        initial: "757",
+    },
+    Register {
+        name: "indirect_address_125",
+        address: 416,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 125](#indirect-address)",
+        initial: "758",
+    },
+    Register {
+        name: "indirect_address_126",
+        address: 418,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 126](#indirect-address)",
+        initial: "759",
+    },
+    Register {
+        name: "indirect_address_127",
+        address: 420,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 127](#indirect-address)",
+        initial: "760",
+    },
+    Register {
+        name: "indirect_address_128",
+        address: 422,
+        size: 2,
+        writable: true,
+        description: "[Indirect Address 128](#indirect-address)",
+        initial: "761",
+    },
+    Register {
+        name: "torque_enable",
+        address: 512,
+        size: 1,
+        writable: true,
+        description: "[Torque Enable](#torque-enable)",
+        initial: "0",
+    },
+    Register {
+        name: "led_red",
+        address: 513,
+        size: 1,
+        writable: true,
+        description: "[LED Red](#led)",
+        initial: "0",
+    },
+    Register {
+        name: "led_green",
+        address: 514,
+        size: 1,
+        writable: true,
+        description: "[LED Green](#led)",
+        initial: "0",
+    },
+    Register {
+        name: "led_blue",
+        address: 515,
+        size: 1,
+        writable: true,
+        description: "[LED Blue](#led)",
+        initial: "0",
+    },
+    Register {
+        name: "status_return_level",
+        address: 516,
+        size: 1,
+        writable: true,
+        description: "[Status Return Level](#status-return-level)",
+        initial: "2",
+    },
+    Register {
+        name: "registered_instruction",
+        address: 517,
+        size: 1,
+        writable: false,
+        description: "[Registered Instruction](#registered-instruction)",
+        initial: "0",
+    },
+    Register {
+        name: "hardware_error_status",
+        address: 518,
+        size: 1,
+        writable: false,
+        description: "[Hardware Error Status](#hardware-error-status)",
+        initial: "0",
+    },
+    Register {
+        name: "velocity_i_gain",
+        address: 524,
+        size: 2,
+        writable: true,
+        description: "[Velocity I Gain](#velocity-i-gain)",
+        initial: "-",
+    },
+    Register {
+        name: "velocity_p_gain",
+        address: 526,
+        size: 2,
+        writable: true,
+        description: "[Velocity P Gain](#velocity-p-gain)",
+        initial: "-",
+    },
+    Register {
+        name: "position_d_gain",
+        address: 528,
+        size: 2,
+        writable: true,
+        description: "[Position D Gain](#position-p-gain)",
+        initial: "-",
+    },
+    Register {
+        name: "position_p_gain",
+        address: 532,
+        size: 2,
+        writable: true,
+        description: "[Position P Gain](#position-p-gain)",
+        initial: "-",
+    },
+    Register {
+        name: "position_i_gain",
+        address: 530,
+        size: 2,
+        writable: true,
+        description: "[Position I Gain](#position-p-gain)",
+        initial: "-",
+    },
+    Register {
+        name: "feedforward_2nd_gain",
+        address: 536,
+        size: 2,
+        writable: true,
+        description: "[Feedforward 2nd Gain](#feedforward-2nd-gain)",
+        initial: "-",
+    },
+    Register {
+        name: "feedforward_1st_gain",
+        address: 538,
+        size: 2,
+        writable: true,
+        description: "[Feedforward 1st Gain](#feedforward-1st-gain)",
+        initial: "-",
+    },
+    Register {
+        name: "bus_watchdog",
+        address: 546,
+        size: 1,
+        writable: true,
+        description: "[Bus Watchdog](#bus-watchdog)",
+        initial: "-",
+    },
+    Register {
+        name: "goal_pwm",
+        address: 548,
+        size: 2,
+        writable: true,
+        description: "[Goal PWM](#goal-pwm)",
+        initial: "-",
+    },
+    Register {
+        name: "goal_current",
+        address: 550,
+        size: 2,
+        writable: true,
+        description: "[Goal Current](#goal-current)",
+        initial: "-",
+    },
+    Register {
+        name: "goal_velocity",
+        address: 552,
+        size: 4,
+        writable: true,
+        description: "[Goal Velocity](#goal-velocity)",
+        initial: "-",
+    },
+    Register {
+        name: "profile_acceleration",
+        address: 556,
+        size: 4,
+        writable: true,
+        description: "[Profile Acceleration](#profile-acceleration)",
+        initial: "-",
+    },
+    Register {
+        name: "profile_velocity",
+        address: 560,
+        size: 4,
+        writable: true,
+        description: "[Profile Velocity](#profile-velocity)",
+        initial: "-",
+    },
+    Register {
+        name: "goal_position",
+        address: 564,
+        size: 4,
+        writable: true,
+        description: "[Goal Position](#goal-position)",
+        initial: "-",
+    },
+    Register {
+        name: "realtime_tick",
+        address: 568,
+        size: 2,
+        writable: false,
+        description: "[Realtime Tick](#realtime-tick)",
+        initial: "-",
+    },
+    Register {
+        name: "moving",
+        address: 570,
+        size: 1,
+        writable: false,
+        description: "[Moving](#moving)",
+        initial: "-",
+    },
+    Register {
+        name: "moving_status",
+        address: 571,
+        size: 1,
+        writable: false,
+        description: "[Moving Status](#moving-status)",
+        initial: "-",
+    },
+    Register {
+        name: "present_pwm",
+        address: 572,
+        size: 2,
+        writable: false,
+        description: "[Present PWM](#present-pwm)",
+        initial: "-",
+    },
+    Register {
+        name: "present_current",
+        address: 574,
+        size: 2,
+        writable: false,
+        description: "[Present Current](#present-current)",
+        initial: "-",
+    },
+    Register {
+        name: "present_velocity",
+        address: 576,
+        size: 4,
+        writable: false,
+        description: "[Present Velocity](#present-velocity)",
+        initial: "-",
+    },
+    Register {
+        name: "present_position",
+        address: 580,
+        size: 4,
+        writable: false,
+        description: "[Present Position](#present-position)",
+        initial: "-",
+    },
+    Register {
+        name: "velocity_trajectory",
+        address: 584,
+        size: 4,
+        writable: false,
+        description: "[Velocity Trajectory](#velocity-trajectory)",
+        initial: "-",
+    },
+    Register {
+        name: "position_trajectory",
+        address: 588,
+        size: 4,
+        writable: false,
+        description: "[Position Trajectory](#position-trajectory)",
+        initial: "-",
+    },
+    Register {
+        name: "present_input_voltage",
+        address: 592,
+        size: 2,
+        writable: false,
+        description: "[Present Input Voltage](#present-input-voltage)",
+        initial: "-",
+    },
+    Register {
+        name: "present_temperature",
+        address: 594,
+        size: 1,
+        writable: false,
+        description: "[Present Temperature](#present-temperature)",
+        initial: "-",
+    },
+    Register {
+        name: "external_port_data_1",
+        address: 600,
+        size: 2,
+        writable: false,
+        description: "[External Port Data 1](#external-port-data)",
+        initial: "0",
+    },
+    Register {
+        name: "external_port_data_2",
+        address: 602,
+        size: 2,
+        writable: false,
+        description: "[External Port Data 2](#external-port-data)",
+        initial: "0",
+    },
+    Register {
+        name: "external_port_data_3",
+        address: 604,
+        size: 2,
+        writable: false,
+        description: "[External Port Data 3](#external-port-data)",
+        initial: "0",
+    },
+    Register {
+        name: "external_port_data_4",
+        address: 606,
+        size: 2,
+        writable: false,
+        description: "[External Port Data 4](#external-port-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_1",
+        address: 634,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 1](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_2",
+        address: 635,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 2](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_3",
+        address: 636,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 3](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_4",
+        address: 637,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 4](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_5",
+        address: 638,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 5](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_6",
+        address: 639,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 6](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_7",
+        address: 640,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 7](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_8",
+        address: 641,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 8](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_9",
+        address: 642,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 9](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_10",
+        address: 643,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 10](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_11",
+        address: 644,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 11](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_12",
+        address: 645,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 12](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_13",
+        address: 646,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 13](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_14",
+        address: 647,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 14](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_15",
+        address: 648,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 15](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_16",
+        address: 649,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 16](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_17",
+        address: 650,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 17](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_18",
+        address: 651,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 18](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_19",
+        address: 652,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 19](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_20",
+        address: 653,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 20](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_21",
+        address: 654,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 21](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_22",
+        address: 655,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 22](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_23",
+        address: 656,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 23](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_24",
+        address: 657,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 24](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_25",
+        address: 658,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 25](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_26",
+        address: 659,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 26](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_27",
+        address: 660,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 27](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_28",
+        address: 661,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 28](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_29",
+        address: 662,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 29](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_30",
+        address: 663,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 30](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_31",
+        address: 664,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 31](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_32",
+        address: 665,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 32](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_33",
+        address: 666,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 33](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_34",
+        address: 667,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 34](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_35",
+        address: 668,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 35](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_36",
+        address: 669,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 36](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_37",
+        address: 670,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 37](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_38",
+        address: 671,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 38](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_39",
+        address: 672,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 39](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_40",
+        address: 673,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 40](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_41",
+        address: 674,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 41](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_42",
+        address: 675,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 42](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_43",
+        address: 676,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 43](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_44",
+        address: 677,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 44](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_45",
+        address: 678,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 45](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_46",
+        address: 679,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 46](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_47",
+        address: 680,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 47](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_48",
+        address: 681,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 48](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_49",
+        address: 682,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 49](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_50",
+        address: 683,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 50](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_51",
+        address: 684,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 51](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_52",
+        address: 685,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 52](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_53",
+        address: 686,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 53](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_54",
+        address: 687,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 54](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_55",
+        address: 688,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 55](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_56",
+        address: 689,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 56](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_57",
+        address: 690,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 57](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_58",
+        address: 691,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 58](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_59",
+        address: 692,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 59](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_60",
+        address: 693,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 60](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_61",
+        address: 694,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 61](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_62",
+        address: 695,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 62](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_63",
+        address: 696,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 63](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_64",
+        address: 697,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 64](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_65",
+        address: 698,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 65](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_66",
+        address: 699,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 66](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_67",
+        address: 700,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 67](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_68",
+        address: 701,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 68](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_69",
+        address: 702,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 69](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_70",
+        address: 703,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 70](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_71",
+        address: 704,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 71](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_72",
+        address: 705,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 72](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_73",
+        address: 706,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 73](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_74",
+        address: 707,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 74](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_75",
+        address: 708,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 75](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_76",
+        address: 709,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 76](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_77",
+        address: 710,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 77](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_78",
+        address: 711,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 78](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_79",
+        address: 712,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 79](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_80",
+        address: 713,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 80](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_81",
+        address: 714,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 81](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_82",
+        address: 715,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 82](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_83",
+        address: 716,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 83](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_84",
+        address: 717,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 84](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_85",
+        address: 718,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 85](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_86",
+        address: 719,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 86](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_87",
+        address: 720,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 87](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_88",
+        address: 721,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 88](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_89",
+        address: 722,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 89](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_90",
+        address: 723,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 90](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_91",
+        address: 724,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 91](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_92",
+        address: 725,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 92](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_93",
+        address: 726,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 93](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_94",
+        address: 727,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 94](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_95",
+        address: 728,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 95](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_96",
+        address: 729,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 96](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_97",
+        address: 730,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 97](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_98",
+        address: 731,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 98](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_99",
+        address: 732,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 99](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_100",
+        address: 733,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 100](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_101",
+        address: 734,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 101](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_102",
+        address: 735,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 102](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_103",
+        address: 736,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 103](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_104",
+        address: 737,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 104](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_105",
+        address: 738,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 105](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_106",
+        address: 739,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 106](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_107",
+        address: 740,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 107](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_108",
+        address: 741,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 108](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_109",
+        address: 742,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 109](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_110",
+        address: 743,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 110](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_111",
+        address: 744,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 111](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_112",
+        address: 745,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 112](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_113",
+        address: 746,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 113](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_114",
+        address: 747,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 114](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_115",
+        address: 748,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 115](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_116",
+        address: 749,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 116](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_117",
+        address: 750,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 117](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_118",
+        address: 751,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 118](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_119",
+        address: 752,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 119](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_120",
+        address: 753,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 120](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_121",
+        address: 754,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 121](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_122",
+        address: 755,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 122](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_123",
+        address: 756,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 123](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_124",
+        address: 757,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 124](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_125",
+        address: 758,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 125](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_126",
+        address: 759,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 126](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_127",
+        address: 760,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 127](#indirect-data)",
+        initial: "0",
+    },
+    Register {
+        name: "indirect_data_128",
+        address: 761,
+        size: 1,
+        writable: true,
+        description: "[Indirect Data 128](#indirect-data)",
+        initial: "0",
+    },
+];
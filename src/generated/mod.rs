@@ -64,3 +64,334 @@ pub mod xw430t200;
 pub mod xw430t333;
 pub mod xw540t140;
 pub mod xw540t260;
+
+use crate::control_table::Model;
+
+/// Models with a documented model number
+pub const MODELS: &[Model] = &[
+    Model {
+        name: "ax12a",
+        model_number: ax12a::MODEL_NUMBER,
+        control_table: ax12a::CONTROL_TABLE,
+    },
+    Model {
+        name: "ax12w",
+        model_number: ax12w::MODEL_NUMBER,
+        control_table: ax12w::CONTROL_TABLE,
+    },
+    Model {
+        name: "ax18a",
+        model_number: ax18a::MODEL_NUMBER,
+        control_table: ax18a::CONTROL_TABLE,
+    },
+    Model {
+        name: "dx113",
+        model_number: dx113::MODEL_NUMBER,
+        control_table: dx113::CONTROL_TABLE,
+    },
+    Model {
+        name: "dx116",
+        model_number: dx116::MODEL_NUMBER,
+        control_table: dx116::CONTROL_TABLE,
+    },
+    Model {
+        name: "dx117",
+        model_number: dx117::MODEL_NUMBER,
+        control_table: dx117::CONTROL_TABLE,
+    },
+    Model {
+        name: "ex106plus",
+        model_number: ex106plus::MODEL_NUMBER,
+        control_table: ex106plus::CONTROL_TABLE,
+    },
+    Model {
+        name: "h4220s300r",
+        model_number: h4220s300r::MODEL_NUMBER,
+        control_table: h4220s300r::CONTROL_TABLE,
+    },
+    Model {
+        name: "h4220s300ra",
+        model_number: h4220s300ra::MODEL_NUMBER,
+        control_table: h4220s300ra::CONTROL_TABLE,
+    },
+    Model {
+        name: "h54100s500r",
+        model_number: h54100s500r::MODEL_NUMBER,
+        control_table: h54100s500r::CONTROL_TABLE,
+    },
+    Model {
+        name: "h54100s500ra",
+        model_number: h54100s500ra::MODEL_NUMBER,
+        control_table: h54100s500ra::CONTROL_TABLE,
+    },
+    Model {
+        name: "h54200s500r",
+        model_number: h54200s500r::MODEL_NUMBER,
+        control_table: h54200s500r::CONTROL_TABLE,
+    },
+    Model {
+        name: "h54200s500ra",
+        model_number: h54200s500ra::MODEL_NUMBER,
+        control_table: h54200s500ra::CONTROL_TABLE,
+    },
+    Model {
+        name: "l4210s300r",
+        model_number: l4210s300r::MODEL_NUMBER,
+        control_table: l4210s300r::CONTROL_TABLE,
+    },
+    Model {
+        name: "l5430s400r",
+        model_number: l5430s400r::MODEL_NUMBER,
+        control_table: l5430s400r::CONTROL_TABLE,
+    },
+    Model {
+        name: "l5430s500r",
+        model_number: l5430s500r::MODEL_NUMBER,
+        control_table: l5430s500r::CONTROL_TABLE,
+    },
+    Model {
+        name: "l5450s290r",
+        model_number: l5450s290r::MODEL_NUMBER,
+        control_table: l5450s290r::CONTROL_TABLE,
+    },
+    Model {
+        name: "l5450s500r",
+        model_number: l5450s500r::MODEL_NUMBER,
+        control_table: l5450s500r::CONTROL_TABLE,
+    },
+    Model {
+        name: "m4210s260r",
+        model_number: m4210s260r::MODEL_NUMBER,
+        control_table: m4210s260r::CONTROL_TABLE,
+    },
+    Model {
+        name: "m4210s260ra",
+        model_number: m4210s260ra::MODEL_NUMBER,
+        control_table: m4210s260ra::CONTROL_TABLE,
+    },
+    Model {
+        name: "m5440s250r",
+        model_number: m5440s250r::MODEL_NUMBER,
+        control_table: m5440s250r::CONTROL_TABLE,
+    },
+    Model {
+        name: "m5440s250ra",
+        model_number: m5440s250ra::MODEL_NUMBER,
+        control_table: m5440s250ra::CONTROL_TABLE,
+    },
+    Model {
+        name: "m5460s250r",
+        model_number: m5460s250r::MODEL_NUMBER,
+        control_table: m5460s250r::CONTROL_TABLE,
+    },
+    Model {
+        name: "m5460s250ra",
+        model_number: m5460s250ra::MODEL_NUMBER,
+        control_table: m5460s250ra::CONTROL_TABLE,
+    },
+    Model {
+        name: "mx106",
+        model_number: mx106::MODEL_NUMBER,
+        control_table: mx106::CONTROL_TABLE,
+    },
+    Model {
+        name: "mx1062",
+        model_number: mx1062::MODEL_NUMBER,
+        control_table: mx1062::CONTROL_TABLE,
+    },
+    Model {
+        name: "mx12w",
+        model_number: mx12w::MODEL_NUMBER,
+        control_table: mx12w::CONTROL_TABLE,
+    },
+    Model {
+        name: "mx28",
+        model_number: mx28::MODEL_NUMBER,
+        control_table: mx28::CONTROL_TABLE,
+    },
+    Model {
+        name: "mx282",
+        model_number: mx282::MODEL_NUMBER,
+        control_table: mx282::CONTROL_TABLE,
+    },
+    Model {
+        name: "mx64",
+        model_number: mx64::MODEL_NUMBER,
+        control_table: mx64::CONTROL_TABLE,
+    },
+    Model {
+        name: "mx642",
+        model_number: mx642::MODEL_NUMBER,
+        control_table: mx642::CONTROL_TABLE,
+    },
+    Model {
+        name: "rx10",
+        model_number: rx10::MODEL_NUMBER,
+        control_table: rx10::CONTROL_TABLE,
+    },
+    Model {
+        name: "rx24f",
+        model_number: rx24f::MODEL_NUMBER,
+        control_table: rx24f::CONTROL_TABLE,
+    },
+    Model {
+        name: "rx28",
+        model_number: rx28::MODEL_NUMBER,
+        control_table: rx28::CONTROL_TABLE,
+    },
+    Model {
+        name: "rx64",
+        model_number: rx64::MODEL_NUMBER,
+        control_table: rx64::CONTROL_TABLE,
+    },
+    Model {
+        name: "xc330m181",
+        model_number: xc330m181::MODEL_NUMBER,
+        control_table: xc330m181::CONTROL_TABLE,
+    },
+    Model {
+        name: "xc330m288",
+        model_number: xc330m288::MODEL_NUMBER,
+        control_table: xc330m288::CONTROL_TABLE,
+    },
+    Model {
+        name: "xc330t181",
+        model_number: xc330t181::MODEL_NUMBER,
+        control_table: xc330t181::CONTROL_TABLE,
+    },
+    Model {
+        name: "xc330t288",
+        model_number: xc330t288::MODEL_NUMBER,
+        control_table: xc330t288::CONTROL_TABLE,
+    },
+    Model {
+        name: "xc430w150",
+        model_number: xc430w150::MODEL_NUMBER,
+        control_table: xc430w150::CONTROL_TABLE,
+    },
+    Model {
+        name: "xc430w240",
+        model_number: xc430w240::MODEL_NUMBER,
+        control_table: xc430w240::CONTROL_TABLE,
+    },
+    Model {
+        name: "xd430t210",
+        model_number: xd430t210::MODEL_NUMBER,
+        control_table: xd430t210::CONTROL_TABLE,
+    },
+    Model {
+        name: "xd430t350",
+        model_number: xd430t350::MODEL_NUMBER,
+        control_table: xd430t350::CONTROL_TABLE,
+    },
+    Model {
+        name: "xd540t150",
+        model_number: xd540t150::MODEL_NUMBER,
+        control_table: xd540t150::CONTROL_TABLE,
+    },
+    Model {
+        name: "xd540t270",
+        model_number: xd540t270::MODEL_NUMBER,
+        control_table: xd540t270::CONTROL_TABLE,
+    },
+    Model {
+        name: "xh430v210",
+        model_number: xh430v210::MODEL_NUMBER,
+        control_table: xh430v210::CONTROL_TABLE,
+    },
+    Model {
+        name: "xh430v350",
+        model_number: xh430v350::MODEL_NUMBER,
+        control_table: xh430v350::CONTROL_TABLE,
+    },
+    Model {
+        name: "xh430w210",
+        model_number: xh430w210::MODEL_NUMBER,
+        control_table: xh430w210::CONTROL_TABLE,
+    },
+    Model {
+        name: "xh430w350",
+        model_number: xh430w350::MODEL_NUMBER,
+        control_table: xh430w350::CONTROL_TABLE,
+    },
+    Model {
+        name: "xh540v150",
+        model_number: xh540v150::MODEL_NUMBER,
+        control_table: xh540v150::CONTROL_TABLE,
+    },
+    Model {
+        name: "xh540v270",
+        model_number: xh540v270::MODEL_NUMBER,
+        control_table: xh540v270::CONTROL_TABLE,
+    },
+    Model {
+        name: "xh540w150",
+        model_number: xh540w150::MODEL_NUMBER,
+        control_table: xh540w150::CONTROL_TABLE,
+    },
+    Model {
+        name: "xh540w270",
+        model_number: xh540w270::MODEL_NUMBER,
+        control_table: xh540w270::CONTROL_TABLE,
+    },
+    Model {
+        name: "xl320",
+        model_number: xl320::MODEL_NUMBER,
+        control_table: xl320::CONTROL_TABLE,
+    },
+    Model {
+        name: "xl330m077",
+        model_number: xl330m077::MODEL_NUMBER,
+        control_table: xl330m077::CONTROL_TABLE,
+    },
+    Model {
+        name: "xl330m288",
+        model_number: xl330m288::MODEL_NUMBER,
+        control_table: xl330m288::CONTROL_TABLE,
+    },
+    Model {
+        name: "xl430w250",
+        model_number: xl430w250::MODEL_NUMBER,
+        control_table: xl430w250::CONTROL_TABLE,
+    },
+    Model {
+        name: "xl430w250test",
+        model_number: xl430w250test::MODEL_NUMBER,
+        control_table: xl430w250test::CONTROL_TABLE,
+    },
+    Model {
+        name: "xm430w210",
+        model_number: xm430w210::MODEL_NUMBER,
+        control_table: xm430w210::CONTROL_TABLE,
+    },
+    Model {
+        name: "xm430w350",
+        model_number: xm430w350::MODEL_NUMBER,
+        control_table: xm430w350::CONTROL_TABLE,
+    },
+    Model {
+        name: "xm540w150",
+        model_number: xm540w150::MODEL_NUMBER,
+        control_table: xm540w150::CONTROL_TABLE,
+    },
+    Model {
+        name: "xm540w270",
+        model_number: xm540w270::MODEL_NUMBER,
+        control_table: xm540w270::CONTROL_TABLE,
+    },
+    Model {
+        name: "xw430t333",
+        model_number: xw430t333::MODEL_NUMBER,
+        control_table: xw430t333::CONTROL_TABLE,
+    },
+    Model {
+        name: "xw540t140",
+        model_number: xw540t140::MODEL_NUMBER,
+        control_table: xw540t140::CONTROL_TABLE,
+    },
+    Model {
+        name: "xw540t260",
+        model_number: xw540t260::MODEL_NUMBER,
+        control_table: xw540t260::CONTROL_TABLE,
+    },
+];
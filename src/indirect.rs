@@ -0,0 +1,296 @@
+//! Registers remapped into a contiguous block by the indirect address table, to read or write
+//! them in one transaction
+//!
+//! X and PRO series only: their `indirect_address_N` registers hold the address of each byte of
+//! the block, which is read and written at `indirect_data_1`.
+
+use crate::control_table::{Model, Register};
+use crate::protocol::{
+    check, sync_read_params, values_vec, Error, Instruction, Protocol, BROADCAST_ID,
+};
+use crate::snapshot::Value;
+use embedded_io::{ErrorType, Read, ReadReady, Write};
+use heapless::Vec;
+
+/// Most registers of a block
+pub const MAX_FIELDS: usize = 16;
+
+/// Most bytes of a block
+pub const MAX_BYTES: usize = 64;
+
+/// Most devices of a sync read
+const MAX_DEVICES: usize = 32;
+
+/// A mapping of registers into the indirect data block
+#[derive(Debug, Clone)]
+pub struct Indirect {
+    pub model: &'static Model,
+    pub fields: Vec<&'static Register, MAX_FIELDS>,
+    address: u16, // of indirect_address_1
+    data: u16,    // of indirect_data_1
+    size: u16,    // in bytes
+}
+
+/// Values of the registers of a block, in the order of the mapping
+#[derive(Debug, Clone)]
+pub struct Block {
+    pub id: u8,
+    pub model: &'static Model,
+    pub values: Vec<Value, MAX_FIELDS>,
+}
+
+impl Block {
+    /// Value of register `name`
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        let register = self.model.register(name)?;
+        self.values
+            .iter()
+            .find(|value| core::ptr::eq(value.register, register))
+    }
+}
+
+impl Indirect {
+    /// Map the registers `names` of this model, in this order, without programming any device
+    ///
+    /// Fails with `UnknownRegister` for a name not in the control table, `Unsupported` if the
+    /// model has no indirect address table, and `TooSmall` if the registers don't fit in it.
+    pub fn new<Serial: ErrorType>(
+        model: &'static Model,
+        names: &[&str],
+    ) -> Result<Self, Error<Serial>> {
+        let address = model
+            .register("indirect_address_1")
+            .ok_or(Error::Unsupported)?
+            .address;
+        let data = model
+            .register("indirect_data_1")
+            .ok_or(Error::Unsupported)?
+            .address;
+        let mut fields = Vec::new();
+        let mut size = 0;
+        for &name in names {
+            let register = model.register(name).ok_or(Error::UnknownRegister)?;
+            fields.push(register).map_err(|_| Error::TooSmall)?;
+            size += u16::from(register.size);
+        }
+        if usize::from(size) > MAX_BYTES.min(capacity(model, address)) {
+            return Err(Error::TooSmall);
+        }
+        Ok(Self {
+            model,
+            fields,
+            address,
+            data,
+            size,
+        })
+    }
+
+    /// Map the registers `names` of this model, and program the indirect address table of
+    /// device `id`
+    pub fn map<P, Serial, const PROTOCOL_VERSION: u8>(
+        dmx: &mut P,
+        id: u8,
+        model: &'static Model,
+        names: &[&str],
+    ) -> Result<Self, Error<Serial>>
+    where
+        P: Protocol<Serial, PROTOCOL_VERSION>,
+        Serial: Read + ReadReady + Write,
+    {
+        let indirect = Self::new(model, names)?;
+        indirect.program(dmx, id)?;
+        Ok(indirect)
+    }
+
+    /// Program the indirect address table of device `id`, whose torque must be disabled
+    pub fn program<P, Serial, const PROTOCOL_VERSION: u8>(
+        &self,
+        dmx: &mut P,
+        id: u8,
+    ) -> Result<(), Error<Serial>>
+    where
+        P: Protocol<Serial, PROTOCOL_VERSION>,
+        Serial: Read + ReadReady + Write,
+    {
+        let mut table = Vec::<u8, { 2 * MAX_BYTES }>::new();
+        for register in &self.fields {
+            for byte in 0..u16::from(register.size) {
+                table
+                    .extend_from_slice(&(register.address + byte).to_le_bytes())
+                    .map_err(|()| Error::TooSmall)?;
+            }
+        }
+        dmx.write::<{ 2 + 2 * MAX_BYTES }, 0>(id, self.address, &table)
+            .map(drop)
+    }
+
+    /// Size of the block, in bytes
+    pub fn size(&self) -> u16 {
+        self.size
+    }
+
+    /// Read the block of device `id`
+    pub fn read<P, Serial, const PROTOCOL_VERSION: u8>(
+        &self,
+        dmx: &mut P,
+        id: u8,
+    ) -> Result<Block, Error<Serial>>
+    where
+        P: Protocol<Serial, PROTOCOL_VERSION>,
+        Serial: Read + ReadReady + Write,
+    {
+        let bytes = dmx.read_vec::<MAX_BYTES>(id, self.data, self.size)?;
+        self.decode(id, &bytes)
+    }
+
+    /// Read the block of several devices at once (Protocol 2 only)
+    ///
+    /// Devices which do not return status for reads are skipped.
+    pub fn sync_read<P, Serial, const PROTOCOL_VERSION: u8, const N: usize>(
+        &self,
+        dmx: &mut P,
+        ids: &[u8],
+    ) -> Result<Vec<Block, N>, Error<Serial>>
+    where
+        P: Protocol<Serial, PROTOCOL_VERSION>,
+        Serial: Read + ReadReady + Write,
+    {
+        let content = sync_read_params::<_, { 4 + MAX_DEVICES }>(self.data, self.size, ids)?;
+        dmx.send(BROADCAST_ID, Instruction::SyncRead, content)?;
+        let mut blocks = Vec::new();
+        for &id in ids {
+            if dmx.returns_status(id, Instruction::SyncRead) {
+                let packet = check(id, dmx.recv_from::<MAX_BYTES>(id)?)?;
+                let bytes = values_vec(packet, self.size)?;
+                blocks
+                    .push(self.decode(id, &bytes)?)
+                    .map_err(|_| Error::TooSmall)?;
+            }
+        }
+        Ok(blocks)
+    }
+
+    /// Write the raw values of the registers of the block of device `id`, in the order of the
+    /// mapping
+    pub fn write<P, Serial, const PROTOCOL_VERSION: u8>(
+        &self,
+        dmx: &mut P,
+        id: u8,
+        values: &[u32],
+    ) -> Result<(), Error<Serial>>
+    where
+        P: Protocol<Serial, PROTOCOL_VERSION>,
+        Serial: Read + ReadReady + Write,
+    {
+        if values.len() != self.fields.len() {
+            return Err(Error::LengthMismatch {
+                id,
+                expected: self.fields.len(),
+                received: values.len(),
+            });
+        }
+        let mut bytes = Vec::<u8, MAX_BYTES>::new();
+        for (register, value) in self.fields.iter().zip(values) {
+            let size = usize::from(register.size).min(4);
+            bytes
+                .extend_from_slice(&value.to_le_bytes()[..size])
+                .map_err(|()| Error::TooSmall)?;
+        }
+        dmx.write::<{ 2 + MAX_BYTES }, 0>(id, self.data, &bytes)
+            .map(drop)
+    }
+
+    fn decode<Serial: ErrorType>(&self, id: u8, bytes: &[u8]) -> Result<Block, Error<Serial>> {
+        let mut values = Vec::new();
+        let mut offset = 0;
+        for &register in &self.fields {
+            let size = usize::from(register.size);
+            let mut raw = [0; 4];
+            raw[..size.min(4)].copy_from_slice(&bytes[offset..offset + size.min(4)]);
+            let value = Value {
+                register,
+                raw: u32::from_le_bytes(raw),
+                unit: self.model.unit(register),
+            };
+            values.push(value).map_err(|_| Error::TooSmall)?;
+            offset += size;
+        }
+        Ok(Block {
+            id,
+            model: self.model,
+            values,
+        })
+    }
+}
+
+/// Entries of the indirect address table from `indirect_address_1`, up to the first one which
+/// is missing or not right after the previous one
+fn capacity(model: &Model, address: u16) -> usize {
+    let mut count = 0;
+    for register in model.control_table {
+        let n = register
+            .name
+            .strip_prefix("indirect_address_")
+            .and_then(|n| n.parse::<u16>().ok());
+        if n == Some(count + 1) && register.address == address + 2 * count {
+            count += 1;
+        }
+    }
+    usize::from(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control_table::model_named;
+
+    type Serial = &'static [u8];
+
+    fn indirect(name: &str, names: &[&str]) -> Result<Indirect, Error<Serial>> {
+        Indirect::new(model_named(name).unwrap(), names)
+    }
+
+    #[test]
+    fn capacity_stops_at_the_first_gap() {
+        let capacity = |name, address| capacity(model_named(name).unwrap(), address);
+        assert_eq!(capacity("xm540w270", 168), 28);
+        assert_eq!(capacity("xl430w250", 168), 28);
+        assert_eq!(capacity("h54200s500r", 49), 256);
+        assert_eq!(capacity("ax12a", 168), 0);
+    }
+
+    #[test]
+    fn new() {
+        let block = indirect(
+            "xl430w250",
+            &["present_position", "present_velocity", "led"],
+        )
+        .unwrap();
+        assert_eq!(block.size(), 9);
+        assert!(matches!(
+            indirect("ax12a", &["led"]),
+            Err(Error::Unsupported)
+        ));
+        assert!(matches!(
+            indirect("xl430w250", &["no_such_register"]),
+            Err(Error::UnknownRegister)
+        ));
+        let names = ["present_position"; 8];
+        assert!(matches!(
+            indirect("xl430w250", &names),
+            Err(Error::TooSmall)
+        ));
+    }
+
+    #[test]
+    fn block_get() {
+        let block = indirect("xl430w250", &["present_position", "led"]).unwrap();
+        let block = block.decode::<Serial>(3, &[0x00, 0x08, 0, 0, 1]).unwrap();
+        assert_eq!(
+            block.get("present-position").map(|value| value.raw),
+            Some(2048)
+        );
+        assert_eq!(block.get("led").map(|value| value.raw), Some(1));
+        assert!(block.get("goal_position").is_none());
+    }
+}
@@ -1,10 +1,23 @@
 #![no_std]
 
+pub mod admin;
+pub mod config;
+pub mod control_table;
+pub mod embedded_hal_02;
 pub mod generated;
+pub mod indirect;
 pub mod protocol;
 pub mod protocol_1;
 pub mod protocol_2;
+#[cfg(feature = "async")]
+pub mod protocol_async;
+pub mod snapshot;
+pub mod stats;
+pub mod trace;
 pub use generated::*;
 
 #[cfg(feature = "std")]
 pub mod serialport_embedded_hal;
+
+#[cfg(feature = "tokio")]
+pub mod tokio_serial_embedded_io;
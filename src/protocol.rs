@@ -1,7 +1,27 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::convert::TryFrom;
 use core::fmt;
-use embedded_hal::{digital::v2::OutputPin, serial};
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+use embedded_io::{ErrorType, Read, ReadReady, Write};
 use heapless::Vec;
 
+use crate::control_table::{Model, Register};
+use crate::stats::Stats;
+use crate::trace::{self, Trace};
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+/// Packets sent to this ID are processed by all devices, which never answer to it
+pub const BROADCAST_ID: u8 = 0xFE;
+
+/// Highest ID a device can have
+pub const MAX_ID: u8 = 0xFC;
+
+/// Packets up to this size are written at once, larger ones in three parts
+pub(crate) const WRITE_BUFFER_SIZE: usize = 128;
+
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd)]
 pub enum Instruction {
@@ -48,11 +68,284 @@ impl From<u8> for Instruction {
     }
 }
 
+/// Parse an instruction from its name in kebab-case, e.g. "sync-write"
+impl core::str::FromStr for Instruction {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, ()> {
+        Ok(match name {
+            "ping" => Self::Ping,
+            "read" => Self::Read,
+            "write" => Self::Write,
+            "reg-write" => Self::RegWrite,
+            "action" => Self::Action,
+            "factory-reset" => Self::FactoryReset,
+            "reboot" => Self::Reboot,
+            "clear" => Self::Clear,
+            "control-table-backup" => Self::ControlTableBackup,
+            "status-return" => Self::StatusReturn,
+            "sync-read" => Self::SyncRead,
+            "sync-write" => Self::SyncWrite,
+            "fast-sync-read" => Self::FastSyncRead,
+            "bulk-read" => Self::BulkRead,
+            "bulk-write" => Self::BulkWrite,
+            "fast-bulk-read" => Self::FastBulkRead,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// What happens to the bytes we send on the bus
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Echo {
+    None,    // TX and RX are separated by a direction pin: we don't receive what we send
+    Discard, // TX and RX are short-circuited: we receive our own packet, which is checked then dropped
+}
+
+/// Which Instruction Packets get a Status Packet in return, from the `status_return_level` register
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd)]
+pub enum StatusReturnLevel {
+    PingOnly = 0, // Status Packet is returned only for the Ping Instruction
+    Read = 1,     // Status Packet is returned only for the Ping and Read Instructions
+    All = 2,      // Status Packet is returned for all Instructions
+}
+
+impl From<u8> for StatusReturnLevel {
+    fn from(val: u8) -> Self {
+        match val {
+            0 => Self::PingOnly,
+            1 => Self::Read,
+            _ => Self::All,
+        }
+    }
+}
+
+impl StatusReturnLevel {
+    /// Whether a device at this level answers to this instruction
+    pub fn returns_status(self, instruction: Instruction) -> bool {
+        match instruction {
+            Instruction::Ping => true,
+            Instruction::Read
+            | Instruction::SyncRead
+            | Instruction::FastSyncRead
+            | Instruction::BulkRead
+            | Instruction::FastBulkRead => self >= Self::Read,
+            _ => self == Self::All,
+        }
+    }
+}
+
+/// What a Factory Reset keeps
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FactoryReset {
+    All = 0xFF,                 // reset everything, the only mode of Protocol 1
+    ExceptId = 0x01,            // keep the ID
+    ExceptIdAndBaudRate = 0x02, // keep the ID and the baud rate
+}
+
+/// What a Control Table Backup does
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Backup {
+    Store = 0x01,   // copy the control table to the backup area
+    Restore = 0x02, // copy the backup area to the control table
+}
+
+/// Params of a Control Table Backup Instruction, after its operation
+pub(crate) const BACKUP_KEY: [u8; 4] = *b"CTRL";
+
+/// Params of a Clear Instruction resetting the multi-turn position
+pub(crate) const CLEAR_MULTI_TURN: [u8; 5] = [0x01, 0x44, 0x58, 0x4C, 0x22];
+
+/// When to release the direction pin, once the serial is flushed
+///
+/// Some serial implementations are flushed when their data register is empty,
+/// while the last byte is still being shifted out. Releasing the direction pin then
+/// truncates the packet on half-duplex transceivers such as the 74LS241.
+#[derive(Debug, Copy, Clone)]
+pub enum Release {
+    Flushed,            // right away: flush waits for the transmission complete flag
+    LastByte,           // after the duration of a byte, computed from the baud rate
+    Hook(fn() -> bool), // when this returns true, eg. by reading the transmission complete flag
+}
+
+/// Serial errors skipped while waiting for a header, when there is no timeout
+const MAX_SKIPPED_ERRORS: u32 = 64;
+
+/// Turnaround and timeouts of the half duplex bus, all durations in µs
+#[derive(Debug, Copy, Clone)]
+pub struct Timing {
+    pub baud_rate: u32,
+    pub release: Release,
+    pub turnaround: u32, // wait after our packet is sent before releasing the direction pin
+    pub guard: u32,      // wait before sending a packet, so that the bus is idle between packets
+    pub return_delay: u32, // devices wait this before answering: 2 µs per unit of their return_delay_time
+    pub latency: u32,      // extra time for the response to reach us, eg. on USB adapters
+    pub timeout_bytes: Option<u32>, // give up after this many byte durations without data, or wait forever
+}
+
+impl Timing {
+    /// No waits, and no timeouts
+    pub const UNTIMED: Self = Self {
+        baud_rate: 57_600,
+        release: Release::Flushed,
+        turnaround: 0,
+        guard: 0,
+        return_delay: 0,
+        latency: 0,
+        timeout_bytes: None,
+    };
+
+    /// Sensible defaults for devices in their factory configuration at this baud rate
+    pub const fn new(baud_rate: u32) -> Self {
+        Self {
+            baud_rate,
+            release: Release::Flushed,
+            turnaround: 0,
+            guard: 0,
+            return_delay: 500,
+            latency: 0,
+            timeout_bytes: Some(16),
+        }
+    }
+
+    /// Duration of a byte on the wire: 1 start bit, 8 data bits, 1 stop bit
+    ///
+    /// A baud rate of 0 counts as the slowest, 1 baud.
+    pub const fn byte(&self) -> u32 {
+        let baud_rate = if self.baud_rate == 0 {
+            1
+        } else {
+            self.baud_rate
+        };
+        let byte = 10_000_000 / baud_rate;
+        if byte == 0 {
+            1
+        } else {
+            byte
+        }
+    }
+
+    /// Deadline for the first byte of a response
+    pub const fn first_byte_timeout(&self) -> Option<u32> {
+        match self.timeout_bytes {
+            Some(n) => Some(
+                self.return_delay
+                    .saturating_add(self.latency)
+                    .saturating_add(n.saturating_mul(self.byte())),
+            ),
+            None => None,
+        }
+    }
+
+    /// Whether `skipped` serial errors, each followed by a byte duration, exceed the deadline
+    /// for the first byte, or MAX_SKIPPED_ERRORS without timeouts
+    pub(crate) const fn skipped_too_long(&self, skipped: u32) -> bool {
+        match self.first_byte_timeout() {
+            Some(timeout) => skipped.saturating_mul(self.byte()) >= timeout,
+            None => skipped >= MAX_SKIPPED_ERRORS,
+        }
+    }
+
+    /// Deadline for each next byte of a packet
+    pub const fn byte_timeout(&self) -> Option<u32> {
+        match self.timeout_bytes {
+            Some(n) => Some(n.saturating_mul(self.byte())),
+            None => None,
+        }
+    }
+}
+
+impl Default for Timing {
+    fn default() -> Self {
+        Self::UNTIMED
+    }
+}
+
+/// When to repeat an operation which failed on a transient bus error
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Retry {
+    pub attempts: u8,        // in total, so 1 never retries
+    pub crc: bool,           // retry on CrcError
+    pub timeout: bool,       // retry on Timeout
+    pub communication: bool, // retry on serial errors, echo mismatches and stray packets
+    pub backoff: u32,        // µs to wait before another attempt
+    pub writes: bool,        // retry writes too: only when writing twice is harmless
+}
+
+impl Retry {
+    pub const NEVER: Self = Self::new(1);
+
+    /// Retry reads and pings on any transient error, without backoff
+    pub const fn new(attempts: u8) -> Self {
+        Self {
+            attempts,
+            crc: true,
+            timeout: true,
+            communication: true,
+            backoff: 0,
+            writes: false,
+        }
+    }
+
+    /// Whether this error may go away on another attempt
+    pub fn retryable<Serial: ErrorType>(&self, error: &Error<Serial>) -> bool {
+        match error {
+            Error::CrcError { .. } => self.crc,
+            Error::Timeout => self.timeout,
+            Error::Communication(_)
+            | Error::EchoMismatch
+            | Error::InstructionReceived { .. }
+            | Error::WrongId { .. } => self.communication,
+            _ => false,
+        }
+    }
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Self::NEVER
+    }
+}
+
+/// How retries went, to monitor the health of the bus
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct RetryCounters {
+    pub retries: u32,   // attempts after the first one
+    pub recovered: u32, // operations which succeeded after a retry
+    pub failed: u32,    // operations which failed even after retries
+}
+
+/// Zero-cost delay for controllers without timing configuration
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NoDelay;
+
+impl DelayNs for NoDelay {
+    fn delay_ns(&mut self, _ns: u32) {}
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::delay::DelayNs for NoDelay {
+    async fn delay_ns(&mut self, _ns: u32) {}
+}
+
 #[derive(Debug)]
-pub struct Controller<Serial, Direction, const PROTOCOL_VERSION: u8> {
+pub struct Controller<Serial, Direction, const PROTOCOL_VERSION: u8, Delay = NoDelay> {
     pub serial: Serial,
     pub direction: Direction,
-    pub n_recv: u8,
+    pub echo: Echo,
+    pub status_return_level: StatusReturnLevel, // for devices which are not in status_return_levels
+    pub status_return_levels: [Option<StatusReturnLevel>; MAX_ID as usize + 1],
+    pub delay: Delay,
+    pub timing: Timing,
+    pub retry: Retry,
+    pub retry_counters: RetryCounters,
+    pub stats: Stats,
+    pub trace: Option<trace::Hook>,
+    #[cfg(feature = "std")]
+    pub(crate) sent_at: Option<(u8, Instruction, Instant)>, // for latency statistics
 }
 
 #[derive(Debug, Clone)]
@@ -64,48 +357,738 @@ pub struct StatusPacket<const MAX_PARAMS_SIZE: usize = 4> {
     pub params: Vec<u8, MAX_PARAMS_SIZE>,
 }
 
-impl<Serial, Direction, const PROTOCOL_VERSION: u8> Controller<Serial, Direction, PROTOCOL_VERSION>
-where
-    Serial: serial::Write<u8> + serial::Read<u8>,
-    Direction: OutputPin,
+impl<const MAX_PARAMS_SIZE: usize> StatusPacket<MAX_PARAMS_SIZE> {
+    /// Error reported by the device, if any.
+    /// The Protocol 2 alert bit, for hardware errors, is not an error of this instruction.
+    pub fn error_code(&self) -> Option<u8> {
+        let error = if self.instruction.is_some() {
+            self.error & 0x7F
+        } else {
+            self.error
+        };
+        (error != 0).then_some(error)
+    }
+}
+
+/// Raw bytes of a packet
+#[derive(Debug)]
+pub struct Frame<'a> {
+    pub head: Vec<u8, 9>, // header, id, length, instruction and error
+    pub params: &'a [u8],
+    pub tail: Vec<u8, 2>, // checksum
+}
+
+impl Frame<'_> {
+    pub fn iter(&self) -> impl Iterator<Item = &u8> + Clone {
+        self.head.iter().chain(self.params).chain(&self.tail)
+    }
+
+    /// The packet in `buffer` if it fits, to write it at once, or else in its three parts
+    pub(crate) fn slices<'b, const N: usize>(
+        &'b self,
+        buffer: &'b mut Vec<u8, N>,
+    ) -> Vec<&'b [u8], 3> {
+        let fits = buffer
+            .extend_from_slice(&self.head)
+            .and_then(|()| buffer.extend_from_slice(self.params))
+            .and_then(|()| buffer.extend_from_slice(&self.tail))
+            .is_ok();
+        let buffer: &'b Vec<u8, N> = buffer;
+        if fits {
+            Vec::from_slice(&[&buffer[..]]).unwrap_or_default()
+        } else {
+            Vec::from_slice(&[&self.head[..], self.params, &self.tail[..]]).unwrap_or_default()
+        }
+    }
+}
+
+/// Length of the longest start of `header` which ends the bytes seen so far,
+/// when `byte` does not continue the `head` first bytes of `header` we had
+pub(crate) fn resync(header: &[u8], head: usize, byte: u8) -> usize {
+    (1..=head)
+        .rev()
+        .find(|&k| header[k - 1] == byte && header[..k - 1] == header[head + 1 - k..head])
+        .unwrap_or(0)
+}
+
+/// Parser of Status Packets, fed byte by byte
+pub trait Decode<const MAX_PARAMS_SIZE: usize> {
+    /// Whether we are still waiting for the header
+    fn in_header(&self) -> bool;
+    /// How many bytes were dropped while looking for the header
+    fn discarded(&self) -> usize;
+    /// Raw bytes of the last packet, as far as it was received
+    fn frame(&self) -> Frame<'_>;
+    /// Give the next byte, and get the packet when it is complete
+    fn push<Serial: ErrorType>(
+        &mut self,
+        byte: u8,
+    ) -> Option<Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>>>;
+}
+
+impl<Serial, Direction, const PROTOCOL_VERSION: u8>
+    Controller<Serial, Direction, PROTOCOL_VERSION>
 {
-    pub const fn new(serial: Serial, direction: Direction, n_recv: u8) -> Self {
+    pub const fn new(serial: Serial, direction: Direction, echo: Echo) -> Self {
         Self {
             serial,
             direction,
-            n_recv,
+            echo,
+            status_return_level: StatusReturnLevel::All,
+            status_return_levels: [None; MAX_ID as usize + 1],
+            delay: NoDelay,
+            timing: Timing::UNTIMED,
+            retry: Retry::NEVER,
+            retry_counters: RetryCounters {
+                retries: 0,
+                recovered: 0,
+                failed: 0,
+            },
+            stats: Stats::new(),
+            trace: None,
+            #[cfg(feature = "std")]
+            sent_at: None,
+        }
+    }
+}
+
+impl<Serial, Direction, const PROTOCOL_VERSION: u8, Delay>
+    Controller<Serial, Direction, PROTOCOL_VERSION, Delay>
+{
+    /// Wait and time out according to `timing`, using `delay`
+    pub fn with_timing<NewDelay>(
+        self,
+        delay: NewDelay,
+        timing: Timing,
+    ) -> Controller<Serial, Direction, PROTOCOL_VERSION, NewDelay> {
+        Controller {
+            serial: self.serial,
+            direction: self.direction,
+            echo: self.echo,
+            status_return_level: self.status_return_level,
+            status_return_levels: self.status_return_levels,
+            delay,
+            timing,
+            retry: self.retry,
+            retry_counters: self.retry_counters,
+            stats: self.stats,
+            trace: self.trace,
+            #[cfg(feature = "std")]
+            sent_at: self.sent_at,
+        }
+    }
+
+    /// Call `hook` with every packet sent or received
+    pub const fn with_trace(mut self, hook: trace::Hook) -> Self {
+        self.trace = Some(hook);
+        self
+    }
+
+    /// Give a packet sent to the trace hook
+    pub(crate) fn trace_sent(&self, id: u8, instruction: Instruction, frame: &Frame<'_>) {
+        if let Some(hook) = self.trace {
+            hook(&Trace {
+                direction: trace::Direction::Sent,
+                timestamp: trace::now(),
+                frame,
+                packet: trace::Packet::Instruction {
+                    id,
+                    instruction,
+                    params: frame.params,
+                },
+            });
+        }
+    }
+
+    /// Give a packet received to the trace hook
+    pub(crate) fn trace_received<const MAX_PARAMS_SIZE: usize>(
+        &self,
+        packet: &StatusPacket<MAX_PARAMS_SIZE>,
+        frame: &Frame<'_>,
+    ) {
+        if let Some(hook) = self.trace {
+            hook(&Trace {
+                direction: trace::Direction::Received,
+                timestamp: trace::now(),
+                frame,
+                packet: trace::Packet::Status {
+                    id: packet.packet_id,
+                    error: packet.error,
+                    params: &packet.params,
+                },
+            });
+        }
+    }
+
+    /// Give a packet received, which could not be decoded, to the trace hook
+    pub(crate) fn trace_broken<E: ErrorType>(&self, error: &Error<E>, frame: &Frame<'_>) {
+        if let Some(hook) = self.trace {
+            let fault = match error {
+                Error::CrcError { .. } => trace::Fault::Checksum,
+                Error::InstructionReceived { .. } => trace::Fault::Instruction,
+                Error::TooLong { .. } => trace::Fault::TooLong,
+                _ => trace::Fault::Truncated,
+            };
+            hook(&Trace {
+                direction: trace::Direction::Received,
+                timestamp: trace::now(),
+                frame,
+                packet: trace::Packet::Broken { fault },
+            });
+        }
+    }
+
+    /// Bus statistics
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = Stats::new();
+    }
+
+    /// Count a sent Instruction Packet
+    pub(crate) fn sent(&mut self, _id: u8, _instruction: Instruction) {
+        self.stats.sent = self.stats.sent.wrapping_add(1);
+        #[cfg(feature = "std")]
+        {
+            self.sent_at = Some((_id, _instruction, Instant::now()));
+        }
+    }
+
+    /// Count the outcome of a reception
+    pub(crate) fn received<const MAX_PARAMS_SIZE: usize, E: ErrorType>(
+        &mut self,
+        discarded: usize,
+        result: &Result<StatusPacket<MAX_PARAMS_SIZE>, Error<E>>,
+    ) {
+        let stats = &mut self.stats;
+        stats.discarded_bytes = stats
+            .discarded_bytes
+            .wrapping_add(u32::try_from(discarded).unwrap_or(u32::MAX));
+        match result {
+            Ok(packet) => {
+                stats.received = stats.received.wrapping_add(1);
+                if packet.error_code().is_some() {
+                    stats.status_error(packet.packet_id);
+                }
+                // only the first answer to the last request, not stale ones
+                #[cfg(feature = "std")]
+                if let Some((id, instruction, sent_at)) = self.sent_at {
+                    if answers(id, packet.packet_id) {
+                        self.sent_at = None;
+                        stats.record_latency(instruction, sent_at.elapsed());
+                    }
+                }
+            }
+            Err(Error::CrcError { .. }) => stats.crc_errors = stats.crc_errors.wrapping_add(1),
+            Err(Error::Timeout) => stats.timeouts = stats.timeouts.wrapping_add(1),
+            Err(_) => {}
         }
     }
+
+    /// Repeat reads, pings, and maybe writes, according to `retry`
+    pub const fn with_retry(mut self, retry: Retry) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// What we know of the status return level of device `id`
+    pub fn status_return_level_of(&self, id: u8) -> StatusReturnLevel {
+        self.status_return_levels
+            .get(usize::from(id))
+            .copied()
+            .flatten()
+            .unwrap_or(self.status_return_level)
+    }
+
+    /// Remember the status return level of device `id`, or of all devices for `BROADCAST_ID`
+    pub fn set_status_return_level_of(&mut self, id: u8, level: StatusReturnLevel) {
+        if id == BROADCAST_ID {
+            self.status_return_level = level;
+            self.status_return_levels = [None; MAX_ID as usize + 1];
+        } else if let Some(known) = self.status_return_levels.get_mut(usize::from(id)) {
+            *known = Some(level);
+        }
+    }
+}
+
+impl<Serial, Direction, const PROTOCOL_VERSION: u8, Delay>
+    Controller<Serial, Direction, PROTOCOL_VERSION, Delay>
+where
+    Serial: Read + ReadReady + Write,
+    Direction: OutputPin,
+    Delay: DelayNs,
+{
+    /// Send bytes in half duplex, and drop their echo if needed
+    pub(crate) fn transmit(
+        &mut self,
+        id: u8,
+        instruction: Instruction,
+        frame: &Frame<'_>,
+    ) -> Result<(), Error<Serial>> {
+        self.trace_sent(id, instruction, frame);
+        let packet = frame.iter();
+        self.delay.delay_us(self.timing.guard);
+        self.direction.set_high().ok();
+        // a single write rather than one per byte, not to stretch the packet on the wire
+        let mut buffer = Vec::<u8, WRITE_BUFFER_SIZE>::new();
+        let sent = frame
+            .slices(&mut buffer)
+            .iter()
+            .try_for_each(|slice| self.serial.write_all(slice))
+            .and_then(|()| self.serial.flush());
+        match self.timing.release {
+            Release::Flushed => {}
+            Release::LastByte => self.delay.delay_us(self.timing.byte()),
+            Release::Hook(complete) => {
+                let timeout = self.timing.byte_timeout();
+                let mut waited = 0;
+                while !complete() && timeout.is_none_or(|timeout| waited < timeout) {
+                    self.delay.delay_us(1);
+                    waited += 1;
+                }
+            }
+        }
+        self.delay.delay_us(self.timing.turnaround);
+        self.direction.set_low().ok();
+        sent.map_err(Error::Communication)?;
+        self.sent(id, instruction);
+
+        // drop our own packet, but check it on the way
+        if self.echo == Echo::Discard {
+            let mut mismatch = false;
+            let mut timeout = self.timing.first_byte_timeout();
+            for &b in packet {
+                mismatch |= self.read_byte(timeout)? != b;
+                timeout = self.timing.byte_timeout();
+            }
+            if mismatch {
+                return Err(Error::EchoMismatch);
+            }
+        }
+        Ok(())
+    }
+
+    /// Wait for a byte, at most `timeout` µs if any
+    pub(crate) fn read_byte(&mut self, timeout: Option<u32>) -> Result<u8, Error<Serial>> {
+        if let Some(timeout) = timeout {
+            let step = self.timing.byte();
+            let mut waited = 0;
+            while !self.serial.read_ready().map_err(Error::Communication)? {
+                if waited >= timeout {
+                    return Err(Error::Timeout);
+                }
+                self.delay.delay_us(step);
+                waited = waited.saturating_add(step);
+            }
+        }
+        let mut byte = [0];
+        match self.serial.read(&mut byte).map_err(Error::Communication)? {
+            0 => Err(Error::Timeout),
+            _ => Ok(byte[0]),
+        }
+    }
+
+    /// Feed received bytes to `decoder`, until it has a complete packet
+    pub(crate) fn receive<D, const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        mut decoder: D,
+    ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>>
+    where
+        D: Decode<MAX_PARAMS_SIZE>,
+    {
+        let mut timeout = self.timing.first_byte_timeout();
+        let mut skipped = 0;
+        let result = loop {
+            let byte = match self.read_byte(timeout) {
+                Ok(byte) => byte,
+                Err(Error::Communication(e)) if decoder.in_header() => {
+                    // skip glitches, but not an error which persists
+                    skipped += 1;
+                    if self.timing.skipped_too_long(skipped) {
+                        break Err(Error::Communication(e));
+                    }
+                    self.delay.delay_us(self.timing.byte());
+                    continue;
+                }
+                Err(e) => break Err(e),
+            };
+            timeout = self.timing.byte_timeout();
+            if let Some(packet) = decoder.push(byte) {
+                break packet;
+            }
+        };
+        match &result {
+            Ok(packet) => self.trace_received(packet, &decoder.frame()),
+            Err(e) if !decoder.in_header() => self.trace_broken(e, &decoder.frame()),
+            Err(_) => {}
+        }
+        self.received(decoder.discarded(), &result);
+        result
+    }
 }
 
 pub enum Error<Serial>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: ErrorType,
 {
-    Communication(<Serial as serial::Read<u8>>::Error),
+    Communication(Serial::Error),
     TooSmall,
     TooManyParams,
-    CrcError,
-    InstructionReceived,
+    CrcError {
+        id: u8,
+    },
+    InstructionReceived {
+        id: u8,
+    },
+    EchoMismatch,
+    NoStatus,
+    Timeout,
+    WrongId {
+        expected: u8,
+        received: u8,
+    },
+    Status {
+        id: u8,
+        error: u8,
+    },
+    LengthMismatch {
+        id: u8,
+        expected: usize,
+        received: usize,
+    },
+    NoDevice {
+        id: u8,
+    },
+    IdInUse {
+        id: u8,
+    },
+    InvalidId {
+        id: u8,
+    },
+    UnknownModel {
+        id: u8,
+        model_number: u16,
+    },
+    UnsupportedBaudRate {
+        baud_rate: u32,
+    },
+    Unsupported,
+    NoBackup {
+        id: u8,
+    },
+    UnknownRegister,
+    TooLong {
+        id: u8,
+    },
+    Untimed,
 }
 
 impl<Serial> fmt::Debug for Error<Serial>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: ErrorType,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self {
-            Self::Communication(_) => f.write_str("Serial read error"),
+        match self {
+            Self::Communication(e) => f.debug_tuple("Communication").field(e).finish(),
+            Self::TooSmall => f.write_str("TooSmall"),
+            Self::TooManyParams => f.write_str("TooManyParams"),
+            Self::CrcError { id } => f.debug_struct("CrcError").field("id", id).finish(),
+            Self::InstructionReceived { id } => f
+                .debug_struct("InstructionReceived")
+                .field("id", id)
+                .finish(),
+            Self::EchoMismatch => f.write_str("EchoMismatch"),
+            Self::NoStatus => f.write_str("NoStatus"),
+            Self::Timeout => f.write_str("Timeout"),
+            Self::WrongId { expected, received } => f
+                .debug_struct("WrongId")
+                .field("expected", expected)
+                .field("received", received)
+                .finish(),
+            Self::Status { id, error } => f
+                .debug_struct("Status")
+                .field("id", id)
+                .field("error", error)
+                .finish(),
+            Self::LengthMismatch {
+                id,
+                expected,
+                received,
+            } => f
+                .debug_struct("LengthMismatch")
+                .field("id", id)
+                .field("expected", expected)
+                .field("received", received)
+                .finish(),
+            Self::NoDevice { id } => f.debug_struct("NoDevice").field("id", id).finish(),
+            Self::IdInUse { id } => f.debug_struct("IdInUse").field("id", id).finish(),
+            Self::InvalidId { id } => f.debug_struct("InvalidId").field("id", id).finish(),
+            Self::UnknownModel { id, model_number } => f
+                .debug_struct("UnknownModel")
+                .field("id", id)
+                .field("model_number", model_number)
+                .finish(),
+            Self::UnsupportedBaudRate { baud_rate } => f
+                .debug_struct("UnsupportedBaudRate")
+                .field("baud_rate", baud_rate)
+                .finish(),
+            Self::Unsupported => f.write_str("Unsupported"),
+            Self::NoBackup { id } => f.debug_struct("NoBackup").field("id", id).finish(),
+            Self::UnknownRegister => f.write_str("UnknownRegister"),
+            Self::TooLong { id } => f.debug_struct("TooLong").field("id", id).finish(),
+            Self::Untimed => f.write_str("Untimed"),
+        }
+    }
+}
+
+impl<Serial> fmt::Display for Error<Serial>
+where
+    Serial: ErrorType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Communication(e) => write!(f, "serial error: {e:?}"),
             Self::TooSmall => f.write_str("MAX_PARAMS_SIZE too small for this packet"),
             Self::TooManyParams => f.write_str("too many params"),
-            e => f.write_fmt(format_args!("{e:?}")),
+            Self::CrcError { id } => write!(f, "wrong checksum in packet from {id}"),
+            Self::InstructionReceived { id } => {
+                write!(
+                    f,
+                    "instruction packet received from {id} instead of a status"
+                )
+            }
+            Self::EchoMismatch => f.write_str("echo does not match the packet sent"),
+            Self::NoStatus => f.write_str("no status is returned for this instruction"),
+            Self::Timeout => f.write_str("no response in time"),
+            Self::WrongId { expected, received } => {
+                write!(f, "response from {received} instead of {expected}")
+            }
+            Self::Status { id, error } => write!(f, "device {id} returned error 0x{error:02x}"),
+            Self::LengthMismatch {
+                id,
+                expected,
+                received,
+            } => write!(
+                f,
+                "{received} bytes received from {id} instead of {expected}"
+            ),
+            Self::NoDevice { id } => write!(f, "device {id} does not answer"),
+            Self::IdInUse { id } => write!(f, "ID {id} is already used"),
+            Self::InvalidId { id } => write!(f, "{id} is not a valid device ID"),
+            Self::UnknownModel { id, model_number } => {
+                write!(f, "device {id} has unknown model number {model_number}")
+            }
+            Self::UnsupportedBaudRate { baud_rate } => {
+                write!(f, "baud rate {baud_rate} is not supported by this model")
+            }
+            Self::Unsupported => f.write_str("not supported by this protocol version"),
+            Self::NoBackup { id } => write!(f, "device {id} has no control table backup"),
+            Self::UnknownRegister => f.write_str("no such register in the control table"),
+            Self::TooLong { id } => {
+                write!(f, "status packet from {id} longer than MAX_PARAMS_SIZE")
+            }
+            Self::Untimed => f.write_str("timeouts are needed: see Controller::with_timing"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Serial> std::error::Error for Error<Serial> where Serial: ErrorType {}
+
+/// Check that this status comes from `id` and reports no error
+pub(crate) fn check<Serial: ErrorType, const MAX_PARAMS_SIZE: usize>(
+    id: u8,
+    packet: StatusPacket<MAX_PARAMS_SIZE>,
+) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>> {
+    if packet.packet_id != id {
+        return Err(Error::WrongId {
+            expected: id,
+            received: packet.packet_id,
+        });
+    }
+    match packet.error_code() {
+        Some(error) => Err(Error::Status { id, error }),
+        None => Ok(packet),
+    }
+}
+
+/// Whether a status from device `from` may answer a request to `id`: any device answers a
+/// broadcast
+pub(crate) fn answers(id: u8, from: u8) -> bool {
+    id == BROADCAST_ID || from == id
+}
+
+/// Whether this error comes from a packet which is not the status of device `id`
+pub(crate) fn foreign<Serial: ErrorType>(id: u8, error: &Error<Serial>) -> bool {
+    match error {
+        Error::InstructionReceived { .. } => true,
+        Error::CrcError { id: from } | Error::TooLong { id: from } => !answers(id, *from),
+        _ => false,
+    }
+}
+
+/// Params of a status, which must be exactly SIZE bytes
+pub(crate) fn values<Serial: ErrorType, const SIZE: usize>(
+    packet: StatusPacket<SIZE>,
+) -> Result<[u8; SIZE], Error<Serial>> {
+    let id = packet.packet_id;
+    packet
+        .params
+        .into_array()
+        .map_err(|params| Error::LengthMismatch {
+            id,
+            expected: SIZE,
+            received: params.len(),
+        })
+}
+
+/// Params of a status, which must be `length` bytes
+pub(crate) fn values_vec<Serial: ErrorType, const MAX_SIZE: usize>(
+    packet: StatusPacket<MAX_SIZE>,
+    length: u16,
+) -> Result<Vec<u8, MAX_SIZE>, Error<Serial>> {
+    if packet.params.len() != usize::from(length) {
+        return Err(Error::LengthMismatch {
+            id: packet.packet_id,
+            expected: usize::from(length),
+            received: packet.params.len(),
+        });
+    }
+    Ok(packet.params)
+}
+
+/// Push an address or a length, on 1 byte for Protocol 1 and 2 bytes for Protocol 2
+fn push_word<Serial: ErrorType, const PROTOCOL_VERSION: u8, const PARAMS_SIZE: usize>(
+    content: &mut Vec<u8, PARAMS_SIZE>,
+    word: u16,
+) -> Result<(), Error<Serial>> {
+    let bytes = word.to_le_bytes();
+    let bytes = if PROTOCOL_VERSION == 2 {
+        &bytes[..]
+    } else {
+        &bytes[..1]
+    };
+    content
+        .extend_from_slice(bytes)
+        .map_err(|()| Error::TooSmall)
+}
+
+pub(crate) fn size<Serial: ErrorType>(size: usize) -> Result<u16, Error<Serial>> {
+    u16::try_from(size).map_err(|_| Error::TooManyParams)
+}
+
+/// Params of a Read Instruction
+pub(crate) fn read_params<Serial: ErrorType, const PROTOCOL_VERSION: u8>(
+    address: u16,
+    size: u16,
+) -> Result<Vec<u8, 4>, Error<Serial>> {
+    let mut content = Vec::new();
+    push_word::<_, PROTOCOL_VERSION, 4>(&mut content, address)?;
+    push_word::<_, PROTOCOL_VERSION, 4>(&mut content, size)?;
+    Ok(content)
+}
+
+/// Params of a Write or a Reg Write Instruction
+pub(crate) fn write_params<
+    Serial: ErrorType,
+    const PROTOCOL_VERSION: u8,
+    const PARAMS_SIZE: usize,
+>(
+    address: u16,
+    data: &[u8],
+) -> Result<Vec<u8, PARAMS_SIZE>, Error<Serial>> {
+    let mut content = Vec::new();
+    push_word::<_, PROTOCOL_VERSION, PARAMS_SIZE>(&mut content, address)?;
+    content
+        .extend_from_slice(data)
+        .map_err(|()| Error::TooSmall)?;
+    Ok(content)
+}
+
+/// Params of a Sync Write Instruction: the same address on each device
+pub(crate) fn sync_write_params<
+    Serial: ErrorType,
+    const PROTOCOL_VERSION: u8,
+    const PARAMS_SIZE: usize,
+    const SIZE: usize,
+>(
+    address: u16,
+    data: &[(u8, [u8; SIZE])],
+) -> Result<Vec<u8, PARAMS_SIZE>, Error<Serial>> {
+    let mut content = Vec::new();
+    push_word::<_, PROTOCOL_VERSION, PARAMS_SIZE>(&mut content, address)?;
+    push_word::<_, PROTOCOL_VERSION, PARAMS_SIZE>(&mut content, size(SIZE)?)?;
+    for (id, bytes) in data {
+        content.push(*id).map_err(|_| Error::TooSmall)?;
+        content
+            .extend_from_slice(bytes)
+            .map_err(|()| Error::TooSmall)?;
+    }
+    Ok(content)
+}
+
+/// Params of a Sync Read Instruction: the same address on each device
+pub(crate) fn sync_read_params<Serial: ErrorType, const PARAMS_SIZE: usize>(
+    address: u16,
+    size: u16,
+    ids: &[u8],
+) -> Result<Vec<u8, PARAMS_SIZE>, Error<Serial>> {
+    let mut content = Vec::new();
+    push_word::<_, 2, PARAMS_SIZE>(&mut content, address)?;
+    push_word::<_, 2, PARAMS_SIZE>(&mut content, size)?;
+    content
+        .extend_from_slice(ids)
+        .map_err(|()| Error::TooSmall)?;
+    Ok(content)
+}
+
+/// Params of a Bulk Read Instruction: an (id, address, size) for each device
+pub(crate) fn bulk_read_params<
+    Serial: ErrorType,
+    const PROTOCOL_VERSION: u8,
+    const PARAMS_SIZE: usize,
+>(
+    reads: &[(u8, u16, u16)],
+) -> Result<Vec<u8, PARAMS_SIZE>, Error<Serial>> {
+    let mut content = Vec::new();
+    if PROTOCOL_VERSION == 1 {
+        content.push(0).map_err(|_| Error::TooSmall)?;
+    }
+    for &(id, address, size) in reads {
+        if PROTOCOL_VERSION == 1 {
+            push_word::<_, 1, PARAMS_SIZE>(&mut content, size)?;
+            content.push(id).map_err(|_| Error::TooSmall)?;
+            push_word::<_, 1, PARAMS_SIZE>(&mut content, address)?;
+        } else {
+            content.push(id).map_err(|_| Error::TooSmall)?;
+            push_word::<_, 2, PARAMS_SIZE>(&mut content, address)?;
+            push_word::<_, 2, PARAMS_SIZE>(&mut content, size)?;
         }
     }
+    Ok(content)
+}
+
+/// Params of a Bulk Write Instruction: an (id, address, data) for each device
+pub(crate) fn bulk_write_params<Serial: ErrorType, const PARAMS_SIZE: usize>(
+    writes: &[(u8, u16, &[u8])],
+) -> Result<Vec<u8, PARAMS_SIZE>, Error<Serial>> {
+    let mut content = Vec::new();
+    for &(id, address, data) in writes {
+        content.push(id).map_err(|_| Error::TooSmall)?;
+        push_word::<_, 2, PARAMS_SIZE>(&mut content, address)?;
+        push_word::<_, 2, PARAMS_SIZE>(&mut content, size(data.len())?)?;
+        content
+            .extend_from_slice(data)
+            .map_err(|()| Error::TooSmall)?;
+    }
+    Ok(content)
 }
 
 pub trait Protocol<Serial, const PROTOCOL_VERSION: u8 = 4>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
 {
     fn send<const MAX_PARAMS_SIZE: usize>(
         &mut self,
@@ -116,10 +1099,505 @@ where
     fn recv<const MAX_PARAMS_SIZE: usize>(
         &mut self,
     ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>>;
-    fn n_recv(&self) -> u8;
+    fn status_return_level(&self, id: u8) -> StatusReturnLevel;
+    /// Remember the status return level of a device, without writing it there
+    fn set_status_return_level(&mut self, id: u8, level: StatusReturnLevel);
+    fn retry_policy(&self) -> Retry;
+    fn retry_counters(&mut self) -> &mut RetryCounters;
+    /// Wait before another attempt
+    fn backoff(&mut self);
 
-    fn ping(&mut self, id: u8) -> Result<bool, Error<Serial>> {
+    /// Whether device `id` will answer to this instruction
+    fn returns_status(&self, id: u8, instruction: Instruction) -> bool {
+        id != BROADCAST_ID && self.status_return_level(id).returns_status(instruction)
+    }
+
+    /// Whether to make another attempt after this error, and count it
+    fn retry_after(&mut self, write: bool, attempt: u8, error: &Error<Serial>) -> bool {
+        let retry = self.retry_policy();
+        let again = attempt < retry.attempts && (!write || retry.writes) && retry.retryable(error);
+        if again {
+            let counters = self.retry_counters();
+            counters.retries = counters.retries.wrapping_add(1);
+        }
+        again
+    }
+
+    /// Count the outcome of an operation which took `attempt` attempts
+    fn retry_done(&mut self, attempt: u8, ok: bool) {
+        if attempt > 1 {
+            let counters = self.retry_counters();
+            if ok {
+                counters.recovered = counters.recovered.wrapping_add(1);
+            } else {
+                counters.failed = counters.failed.wrapping_add(1);
+            }
+        }
+    }
+
+    /// Receive the status of device `id`, dropping stale ones from other devices,
+    /// e.g. late answers to a request which timed out
+    ///
+    /// Broken packets from other devices, and instruction packets, are dropped too:
+    /// only errors of the status of device `id` are returned. Any device answers
+    /// `BROADCAST_ID`, e.g. a broadcast ping.
+    fn recv_from<const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+    ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>> {
+        loop {
+            match self.recv() {
+                Ok(packet) if !answers(id, packet.packet_id) => {}
+                Err(e) if foreign(id, &e) => {}
+                result => return result,
+            }
+        }
+    }
+
+    /// Ping device `id` once, without retries
+    fn ping_once(&mut self, id: u8) -> Result<(), Error<Serial>> {
         self.send(id, Instruction::Ping, Vec::<u8, 0>::new())?;
-        Ok(self.recv::<3>().is_ok())
+        self.recv_from::<3>(id).map(drop)
+    }
+
+    /// Whether device `id` answers: false if it does not in time, an error if the bus fails
+    fn ping(&mut self, id: u8) -> Result<bool, Error<Serial>> {
+        let mut attempt = 1;
+        let result = loop {
+            match self.ping_once(id) {
+                Err(e) if self.retry_after(false, attempt, &e) => self.backoff(),
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        match result {
+            Ok(()) => Ok(true),
+            Err(Error::Timeout) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Read once, without retries
+    fn read_once<const SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+    ) -> Result<[u8; SIZE], Error<Serial>> {
+        if !self.returns_status(id, Instruction::Read) {
+            return Err(Error::NoStatus);
+        }
+        let content = read_params::<_, PROTOCOL_VERSION>(address, size(SIZE)?)?;
+        self.send(id, Instruction::Read, content)?;
+        values(check(id, self.recv_from::<SIZE>(id)?)?)
+    }
+
+    /// Read SIZE bytes from the control table of device `id`, starting at `address`
+    fn read<const SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+    ) -> Result<[u8; SIZE], Error<Serial>> {
+        let mut attempt = 1;
+        let result = loop {
+            match self.read_once(id, address) {
+                Err(e) if self.retry_after(false, attempt, &e) => self.backoff(),
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        result
+    }
+
+    /// Read `length` bytes once, without retries
+    fn read_vec_once<const MAX_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        length: u16,
+    ) -> Result<Vec<u8, MAX_SIZE>, Error<Serial>> {
+        if !self.returns_status(id, Instruction::Read) {
+            return Err(Error::NoStatus);
+        }
+        if usize::from(length) > MAX_SIZE {
+            return Err(Error::TooSmall);
+        }
+        let content = read_params::<_, PROTOCOL_VERSION>(address, length)?;
+        self.send(id, Instruction::Read, content)?;
+        values_vec(check(id, self.recv_from::<MAX_SIZE>(id)?)?, length)
+    }
+
+    /// Read `length` bytes, up to MAX_SIZE, from the control table of device `id`,
+    /// starting at `address`
+    fn read_vec<const MAX_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        length: u16,
+    ) -> Result<Vec<u8, MAX_SIZE>, Error<Serial>> {
+        let mut attempt = 1;
+        let result = loop {
+            match self.read_vec_once(id, address, length) {
+                Err(e) if self.retry_after(false, attempt, &e) => self.backoff(),
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        result
+    }
+
+    /// Write once, without retries
+    fn write_once<const PARAMS_SIZE: usize, const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        data: &[u8],
+    ) -> Result<Option<StatusPacket<MAX_PARAMS_SIZE>>, Error<Serial>> {
+        let content = write_params::<_, PROTOCOL_VERSION, PARAMS_SIZE>(address, data)?;
+        self.send(id, Instruction::Write, content)?;
+        if self.returns_status(id, Instruction::Write) {
+            Ok(Some(check(id, self.recv_from(id)?)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Write `data` in the control table of device `id`, starting at `address`
+    ///
+    /// PARAMS_SIZE must hold the address and the data.
+    /// The Status Packet is received only if the device is expected to send one.
+    /// This is retried only if the retry policy allows writes.
+    fn write<const PARAMS_SIZE: usize, const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        data: &[u8],
+    ) -> Result<Option<StatusPacket<MAX_PARAMS_SIZE>>, Error<Serial>> {
+        let mut attempt = 1;
+        let result = loop {
+            match self.write_once::<PARAMS_SIZE, MAX_PARAMS_SIZE>(id, address, data) {
+                Err(e) if self.retry_after(true, attempt, &e) => self.backoff(),
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        result
+    }
+
+    /// Write SIZE bytes at `address` on several devices at once
+    fn sync_write<const PARAMS_SIZE: usize, const SIZE: usize>(
+        &mut self,
+        address: u16,
+        data: &[(u8, [u8; SIZE])],
+    ) -> Result<(), Error<Serial>> {
+        let content = sync_write_params::<_, PROTOCOL_VERSION, PARAMS_SIZE, SIZE>(address, data)?;
+        self.send(BROADCAST_ID, Instruction::SyncWrite, content)
+    }
+
+    /// Read SIZE bytes at `address` from several devices at once (Protocol 2 only)
+    ///
+    /// Devices which do not return status for reads are skipped.
+    fn sync_read<const PARAMS_SIZE: usize, const SIZE: usize, const N: usize>(
+        &mut self,
+        address: u16,
+        ids: &[u8],
+    ) -> Result<Vec<(u8, [u8; SIZE]), N>, Error<Serial>> {
+        let content = sync_read_params::<_, PARAMS_SIZE>(address, size(SIZE)?, ids)?;
+        self.send(BROADCAST_ID, Instruction::SyncRead, content)?;
+        let mut read = Vec::new();
+        for &id in ids {
+            if self.returns_status(id, Instruction::SyncRead) {
+                let value = values(check(id, self.recv_from::<SIZE>(id)?)?)?;
+                read.push((id, value)).map_err(|_| Error::TooSmall)?;
+            }
+        }
+        Ok(read)
+    }
+
+    /// Read (id, address, size) from several devices at once
+    ///
+    /// Devices which do not return status for reads are skipped.
+    fn bulk_read<const PARAMS_SIZE: usize, const MAX_PARAMS_SIZE: usize, const N: usize>(
+        &mut self,
+        reads: &[(u8, u16, u16)],
+    ) -> Result<Vec<StatusPacket<MAX_PARAMS_SIZE>, N>, Error<Serial>> {
+        let content = bulk_read_params::<_, PROTOCOL_VERSION, PARAMS_SIZE>(reads)?;
+        self.send(BROADCAST_ID, Instruction::BulkRead, content)?;
+        let mut packets = Vec::new();
+        for &(id, _, _) in reads {
+            if self.returns_status(id, Instruction::BulkRead) {
+                let packet = check(id, self.recv_from(id)?)?;
+                packets.push(packet).map_err(|_| Error::TooSmall)?;
+            }
+        }
+        Ok(packets)
+    }
+
+    /// Write (id, address, data) on several devices at once (Protocol 2 only)
+    fn bulk_write<const PARAMS_SIZE: usize>(
+        &mut self,
+        writes: &[(u8, u16, &[u8])],
+    ) -> Result<(), Error<Serial>> {
+        let content = bulk_write_params::<_, PARAMS_SIZE>(writes)?;
+        self.send(BROADCAST_ID, Instruction::BulkWrite, content)
+    }
+
+    /// Send an instruction, and check its status if device `id` returns one
+    fn command<const PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        instruction: Instruction,
+        params: Vec<u8, PARAMS_SIZE>,
+    ) -> Result<(), Error<Serial>> {
+        self.send(id, instruction, params)?;
+        if self.returns_status(id, instruction) {
+            check(id, self.recv_from::<0>(id)?)?;
+        }
+        Ok(())
+    }
+
+    /// Reboot device `id`, whose status may never come if it restarts first
+    fn reboot(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        match self.command(id, Instruction::Reboot, Vec::<u8, 0>::new()) {
+            Err(Error::Timeout) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Reset the control table of device `id` to its factory settings
+    ///
+    /// Protocol 1 only resets everything, including the ID and the baud rate.
+    fn factory_reset(&mut self, id: u8, mode: FactoryReset) -> Result<(), Error<Serial>> {
+        let mut params = Vec::<u8, 1>::new();
+        if PROTOCOL_VERSION == 1 {
+            if mode != FactoryReset::All {
+                return Err(Error::Unsupported);
+            }
+        } else {
+            params.push(mode as u8).map_err(|_| Error::TooSmall)?;
+        }
+        self.command(id, Instruction::FactoryReset, params)
+    }
+
+    /// Reset the multi-turn position of device `id` to within a turn (Protocol 2 only)
+    fn clear_multi_turn(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        if PROTOCOL_VERSION == 1 {
+            return Err(Error::Unsupported);
+        }
+        let params = Vec::<u8, 5>::from_slice(&CLEAR_MULTI_TURN).map_err(|()| Error::TooSmall)?;
+        self.command(id, Instruction::Clear, params)
+    }
+
+    /// Store the control table of device `id` to its backup area, or restore it from there
+    /// (Protocol 2 only)
+    fn control_table_backup(&mut self, id: u8, operation: Backup) -> Result<(), Error<Serial>> {
+        if PROTOCOL_VERSION == 1 {
+            return Err(Error::Unsupported);
+        }
+        let mut params = Vec::<u8, 5>::new();
+        params
+            .push(operation as u8)
+            .map_err(|_| Error::TooSmall)
+            .and_then(|()| {
+                params
+                    .extend_from_slice(&BACKUP_KEY)
+                    .map_err(|()| Error::TooSmall)
+            })?;
+        self.command(id, Instruction::ControlTableBackup, params)
+    }
+
+    /// Register a write of `data` at `address` on device `id`, done on the next Action
+    ///
+    /// PARAMS_SIZE must hold the address and the data.
+    /// This is retried only if the retry policy allows writes.
+    fn reg_write<const PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        data: &[u8],
+    ) -> Result<(), Error<Serial>> {
+        let params = write_params::<_, PROTOCOL_VERSION, PARAMS_SIZE>(address, data)?;
+        let mut attempt = 1;
+        let result = loop {
+            match self.command(id, Instruction::RegWrite, params.clone()) {
+                Err(e) if self.retry_after(true, attempt, &e) => self.backoff(),
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        result
+    }
+
+    /// Register a write of `value` in `register` of device `id`, done on the next Action
+    fn stage(&mut self, id: u8, register: &Register, value: u32) -> Result<(), Error<Serial>> {
+        let size = usize::from(register.size).min(4);
+        self.reg_write::<6>(id, register.address, &value.to_le_bytes()[..size])
+    }
+
+    /// Do the writes registered on device `id`, or on every device with [`BROADCAST_ID`]
+    fn action(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        self.command(id, Instruction::Action, Vec::<u8, 0>::new())
+    }
+
+    /// Whether device `id`, of this model, has a registered write waiting for an Action
+    fn registered(&mut self, id: u8, model: &Model) -> Result<bool, Error<Serial>> {
+        let register = model
+            .register("registered_instruction")
+            .or_else(|| model.register("registered"))
+            .ok_or(Error::Unsupported)?;
+        Ok(self.read::<1>(id, register.address)? != [0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol_2::frame;
+    use core::convert::Infallible;
+
+    /// A bus on which devices answer with `rx`, and which counts the writes of what we send
+    struct Bus {
+        rx: Vec<u8, 64>,
+        read: usize,
+        writes: usize,
+    }
+
+    impl ErrorType for Bus {
+        type Error = Infallible;
+    }
+
+    impl Read for Bus {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+            let n = buf.len().min(self.rx.len() - self.read);
+            buf[..n].copy_from_slice(&self.rx[self.read..self.read + n]);
+            self.read += n;
+            Ok(n)
+        }
+    }
+
+    impl ReadReady for Bus {
+        fn read_ready(&mut self) -> Result<bool, Infallible> {
+            Ok(self.read < self.rx.len())
+        }
+    }
+
+    impl Write for Bus {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+            self.writes += 1;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    struct Pin;
+
+    impl embedded_hal::digital::ErrorType for Pin {
+        type Error = Infallible;
+    }
+
+    impl OutputPin for Pin {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    /// Status of a ping of device `id`
+    fn pong(rx: &mut Vec<u8, 64>, id: u8) {
+        let frame = frame::<Bus>(id, Instruction::StatusReturn, &[0, 0x5E, 0x01, 0x2A]).unwrap();
+        rx.extend(frame.iter().copied());
+    }
+
+    fn controller(rx: Vec<u8, 64>) -> Controller<Bus, Pin, 2> {
+        Controller::new_2(
+            Bus {
+                rx,
+                read: 0,
+                writes: 0,
+            },
+            Pin,
+            Echo::None,
+        )
+    }
+
+    #[test]
+    fn timing() {
+        let timing = Timing::new(1_000_000);
+        assert_eq!(timing.byte(), 10);
+        assert_eq!(timing.first_byte_timeout(), Some(500 + 16 * 10));
+        let slowest = Timing::new(0);
+        assert_eq!(slowest.byte(), 10_000_000);
+        let slowest = Timing {
+            timeout_bytes: Some(1000),
+            ..slowest
+        };
+        assert_eq!(slowest.first_byte_timeout(), Some(u32::MAX));
+        assert_eq!(slowest.byte_timeout(), Some(u32::MAX));
+    }
+
+    #[test]
+    fn ping() {
+        let mut rx = Vec::new();
+        pong(&mut rx, 1);
+        let mut dmx = controller(rx);
+        assert!(matches!(dmx.ping(1), Ok(true)));
+        assert_eq!(dmx.serial.writes, 1);
+        assert!(matches!(dmx.ping(1), Ok(false)));
+    }
+
+    #[test]
+    fn drop_stale_status() {
+        let mut rx = Vec::new();
+        pong(&mut rx, 2);
+        let broken = rx.len() - 1;
+        rx[broken] ^= 0xFF;
+        pong(&mut rx, 3);
+        pong(&mut rx, 1);
+        let mut dmx = controller(rx);
+        assert!(matches!(dmx.ping(1), Ok(true)));
+        assert_eq!(dmx.serial.read, dmx.serial.rx.len());
+        assert_eq!((dmx.stats.sent, dmx.stats.crc_errors), (1, 1));
+        #[cfg(feature = "std")]
+        assert_eq!(dmx.stats.latency_of(Instruction::Ping).count, 1);
+    }
+
+    #[test]
+    fn broadcast_ping() {
+        let mut rx = Vec::new();
+        pong(&mut rx, 3);
+        let mut dmx = controller(rx);
+        assert!(matches!(dmx.ping(BROADCAST_ID), Ok(true)));
+    }
+
+    #[test]
+    fn write_id_drops_stale_status() {
+        let mut rx = Vec::new();
+        let stale = frame::<Bus>(3, Instruction::StatusReturn, &[0x04]).unwrap();
+        rx.extend(stale.iter().copied());
+        let status = frame::<Bus>(2, Instruction::StatusReturn, &[0]).unwrap();
+        rx.extend(status.iter().copied());
+        let mut dmx = controller(rx);
+        assert!(crate::admin::write_id(&mut dmx, 1, 7, 2).is_ok());
+        assert_eq!(dmx.serial.read, dmx.serial.rx.len());
+    }
+
+    #[test]
+    fn crc_error_of_the_awaited_status() {
+        let mut rx = Vec::new();
+        pong(&mut rx, 1);
+        let broken = rx.len() - 1;
+        rx[broken] ^= 0xFF;
+        let mut dmx = controller(rx);
+        assert!(matches!(dmx.ping(1), Err(Error::CrcError { id: 1 })));
     }
 }
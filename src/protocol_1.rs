@@ -1,21 +1,176 @@
 //! ref <https://emanual.robotis.com/docs/en/dxl/protocol1>
 
-use crate::protocol::{Controller, Error, Instruction, Protocol, StatusPacket};
+use crate::protocol::{
+    resync, Controller, Decode, Echo, Error, Frame, Instruction, Protocol, Retry, RetryCounters,
+    StatusPacket, StatusReturnLevel,
+};
 use core::convert::TryInto;
 use core::num::Wrapping;
-use embedded_hal::{digital::v2::OutputPin, serial};
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+use embedded_io::{ErrorType, Read, ReadReady, Write};
 use heapless::Vec;
-use nb::block;
 
 const HEADER: [u8; 2] = [0xFF, 0xFF];
 
-impl<Serial, Direction> Protocol<Serial, 1> for Controller<Serial, Direction, 1>
+/// Build the Instruction Packet for these params
+pub fn frame<Serial: ErrorType>(
+    id: u8,
+    instruction: Instruction,
+    params: &[u8],
+) -> Result<Frame<'_>, Error<Serial>> {
+    let length = (params.len() + 2)
+        .try_into()
+        .map_err(|_| Error::TooManyParams)?;
+    let content = [id, length, instruction as u8];
+    let mut sumcheck = Wrapping(0);
+    for &p in content.iter().chain(params) {
+        sumcheck += Wrapping(p);
+    }
+    let mut head = Vec::new();
+    head.extend_from_slice(&HEADER)
+        .and_then(|()| head.extend_from_slice(&content))
+        .map_err(|()| Error::TooSmall)?;
+    let mut tail = Vec::new();
+    tail.push(!(sumcheck.0)).map_err(|_| Error::TooSmall)?;
+    Ok(Frame { head, params, tail })
+}
+
+/// Parser of Protocol 1 Status Packets
+#[derive(Debug, Clone)]
+pub struct Decoder<const MAX_PARAMS_SIZE: usize> {
+    head: usize,
+    discarded: usize, // bytes before the header
+    position: usize,  // after the header
+    packet_id: u8,
+    length: u8,
+    error: u8,
+    params: Vec<u8, MAX_PARAMS_SIZE>,
+    checksum: u8,
+}
+
+impl<const MAX_PARAMS_SIZE: usize> Decoder<MAX_PARAMS_SIZE> {
+    pub const fn new() -> Self {
+        Self {
+            head: 0,
+            discarded: 0,
+            position: 0,
+            packet_id: 0,
+            length: 0,
+            error: 0,
+            params: Vec::new(),
+            checksum: 0,
+        }
+    }
+}
+
+impl<const MAX_PARAMS_SIZE: usize> Default for Decoder<MAX_PARAMS_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const MAX_PARAMS_SIZE: usize> Decode<MAX_PARAMS_SIZE> for Decoder<MAX_PARAMS_SIZE> {
+    fn in_header(&self) -> bool {
+        self.head < HEADER.len()
+    }
+
+    fn discarded(&self) -> usize {
+        self.discarded
+    }
+
+    fn frame(&self) -> Frame<'_> {
+        let content = [self.packet_id, self.length, self.error];
+        let n_params = usize::from(self.length).saturating_sub(2);
+        let checksum = self.position.saturating_sub(3 + n_params).min(1);
+        let mut head = Vec::new();
+        head.extend_from_slice(&HEADER).ok();
+        head.extend_from_slice(&content[..self.position.min(3)])
+            .ok();
+        Frame {
+            head,
+            params: &self.params,
+            tail: Vec::from_slice(&[self.checksum][..checksum]).unwrap_or_default(),
+        }
+    }
+
+    fn push<Serial: ErrorType>(
+        &mut self,
+        byte: u8,
+    ) -> Option<Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>>> {
+        // wait for HEADER
+        if self.in_header() {
+            if byte == HEADER[self.head] {
+                self.head += 1;
+            } else {
+                let head = resync(&HEADER, self.head, byte);
+                self.discarded += self.head + 1 - head;
+                self.head = head;
+            }
+            return None;
+        }
+
+        // read content
+        let n_params = usize::from(self.length).saturating_sub(2);
+        self.position += 1;
+        match self.position {
+            1 => self.packet_id = byte,
+            2 => {
+                self.length = byte;
+                if usize::from(byte).saturating_sub(2) > MAX_PARAMS_SIZE {
+                    return Some(Err(Error::TooLong { id: self.packet_id }));
+                }
+            }
+            3 => self.error = byte,
+            p if p <= 3 + n_params => {
+                if self.params.push(byte).is_err() {
+                    return Some(Err(Error::TooLong { id: self.packet_id }));
+                }
+            }
+            _ => {
+                self.checksum = byte;
+                let mut sumcheck =
+                    Wrapping(self.packet_id) + Wrapping(self.length) + Wrapping(self.error);
+                for &p in self.params.iter() {
+                    sumcheck += Wrapping(p);
+                }
+
+                return Some(if byte == !(sumcheck.0) {
+                    Ok(StatusPacket {
+                        packet_id: self.packet_id,
+                        length: usize::from(self.length),
+                        instruction: None,
+                        error: self.error,
+                        params: self.params.clone(),
+                    })
+                } else {
+                    Err(Error::CrcError { id: self.packet_id })
+                });
+            }
+        }
+        None
+    }
+}
+
+impl<Serial, Direction, Delay> Protocol<Serial, 1> for Controller<Serial, Direction, 1, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
     Direction: OutputPin,
+    Delay: DelayNs,
 {
-    fn n_recv(&self) -> u8 {
-        self.n_recv
+    fn status_return_level(&self, id: u8) -> StatusReturnLevel {
+        self.status_return_level_of(id)
+    }
+    fn set_status_return_level(&mut self, id: u8, level: StatusReturnLevel) {
+        self.set_status_return_level_of(id, level);
+    }
+    fn retry_policy(&self) -> Retry {
+        self.retry
+    }
+    fn retry_counters(&mut self) -> &mut RetryCounters {
+        &mut self.retry_counters
+    }
+    fn backoff(&mut self) {
+        self.delay.delay_us(self.retry.backoff);
     }
     fn send<const MAX_PARAMS_SIZE: usize>(
         &mut self,
@@ -23,83 +178,95 @@ where
         instruction: Instruction,
         params: Vec<u8, MAX_PARAMS_SIZE>,
     ) -> Result<(), Error<Serial>> {
-        let length = (params.len() + 2)
-            .try_into()
-            .map_err(|_| Error::TooManyParams)?;
-        let content = [id, length, instruction as u8];
-        let mut sumcheck = Wrapping(0);
-
-        self.direction.set_high().ok();
-        for &b in &HEADER {
-            block!(self.serial.write(b)).ok();
-        }
-        for &p in content.iter().chain(params.as_slice()) {
-            sumcheck += Wrapping(p);
-            block!(self.serial.write(p)).ok();
-        }
-        block!(self.serial.write(!(sumcheck.0))).ok();
-        block!(self.serial.flush()).ok();
-        self.direction.set_low().ok();
-        Ok(())
+        let frame = frame(id, instruction, &params)?;
+        self.transmit(id, instruction, &frame)
     }
 
     fn recv<const MAX_PARAMS_SIZE: usize>(
         &mut self,
     ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>> {
-        // wait for HEADER
-        let mut head = 0;
-        loop {
-            match block!(self.serial.read()) {
-                Ok(b) if b == HEADER[head] => head += 1,
-                _ => head = 0,
-            }
-            if head == HEADER.len() {
-                break;
-            }
-        }
+        self.receive(Decoder::new())
+    }
+}
 
-        // read content
-        let packet_id: u8 = block!(self.serial.read()).map_err(Error::Communication)?;
-        let length: u8 = block!(self.serial.read()).map_err(Error::Communication)?;
-        if usize::from(length) - 2 < MAX_PARAMS_SIZE {
-            return Err(Error::TooSmall);
-        }
-        let error: u8 = block!(self.serial.read()).map_err(Error::Communication)?;
-        let mut params = Vec::new();
-        for _ in 0..(length - 2) {
-            params
-                .push(block!(self.serial.read()).map_err(Error::Communication)?)
-                .map_err(|_| Error::TooSmall)?;
-        }
-        let checksum: u8 = block!(self.serial.read()).map_err(Error::Communication)?;
+impl<Serial, Direction> Controller<Serial, Direction, 1> {
+    pub const fn new_1(serial: Serial, direction: Direction, echo: Echo) -> Self {
+        Self::new(serial, direction, echo)
+    }
+}
 
-        let mut sumcheck = Wrapping(packet_id) + Wrapping(length) + Wrapping(error);
-        for &p in params.iter() {
-            sumcheck += Wrapping(p);
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        if checksum == !(sumcheck.0) {
-            let length = length as usize;
-            let instruction = None;
-            Ok(StatusPacket {
-                packet_id,
-                length,
-                instruction,
-                error,
-                params,
-            })
-        } else {
-            Err(Error::CrcError)
-        }
+    type Serial = &'static [u8];
+
+    fn status(id: u8, error: u8, params: &[u8]) -> Vec<u8, 32> {
+        let mut bytes = Vec::from_slice(&[0xFF, 0xFF, id, params.len() as u8 + 2, error]).unwrap();
+        bytes.extend_from_slice(params).unwrap();
+        let sum = bytes[2..].iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        bytes.push(!sum).unwrap();
+        bytes
     }
-}
 
-impl<Serial, Direction> Controller<Serial, Direction, 1>
-where
-    Serial: serial::Write<u8> + serial::Read<u8>,
-    Direction: OutputPin,
-{
-    pub const fn new_1(serial: Serial, direction: Direction, n_recv: u8) -> Self {
-        Self::new(serial, direction, n_recv)
+    fn decode<const N: usize>(
+        decoder: &mut Decoder<N>,
+        bytes: &[u8],
+    ) -> Option<Result<StatusPacket<N>, Error<Serial>>> {
+        bytes.iter().find_map(|&b| decoder.push(b))
+    }
+
+    #[test]
+    fn status_packet() {
+        let mut decoder = Decoder::<4>::new();
+        let packet = decode(&mut decoder, &status(1, 0, &[0x20, 0x03]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(packet.packet_id, 1);
+        assert_eq!(packet.params, [0x20, 0x03]);
+        assert_eq!(packet.error_code(), None);
+        assert_eq!(decoder.discarded(), 0);
+    }
+
+    #[test]
+    fn status_error() {
+        let packet = decode(&mut Decoder::<4>::new(), &status(1, 0x20, &[]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(packet.error_code(), Some(0x20));
+    }
+
+    #[test]
+    fn resync_after_noise() {
+        let mut bytes = Vec::<u8, 40>::from_slice(&[0x00, 0xFF, 0x12]).unwrap();
+        bytes.extend_from_slice(&status(2, 0, &[7])).unwrap();
+        let mut decoder = Decoder::<4>::new();
+        let packet = decode(&mut decoder, &bytes).unwrap().unwrap();
+        assert_eq!((packet.packet_id, &packet.params[..]), (2, &[7][..]));
+        assert_eq!(decoder.discarded(), 3);
+    }
+
+    #[test]
+    fn checksum_error() {
+        let mut bytes = status(4, 0, &[1, 2]);
+        *bytes.last_mut().unwrap() ^= 0xFF;
+        let result = decode(&mut Decoder::<4>::new(), &bytes).unwrap();
+        assert!(matches!(result, Err(Error::CrcError { id: 4 })));
+    }
+
+    #[test]
+    fn too_long() {
+        let result = decode(&mut Decoder::<1>::new(), &status(6, 0, &[1, 2])).unwrap();
+        assert!(matches!(result, Err(Error::TooLong { id: 6 })));
+    }
+
+    #[test]
+    fn truncated_frame() {
+        let bytes = status(7, 0, &[1, 2]);
+        let mut decoder = Decoder::<4>::new();
+        assert!(decode(&mut decoder, &bytes[..6]).is_none());
+        assert!(decoder.frame().iter().eq(&bytes[..6]));
+        assert!(decode(&mut decoder, &bytes[6..]).is_some());
+        assert!(decoder.frame().iter().eq(&bytes[..]));
     }
 }
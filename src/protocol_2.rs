@@ -1,9 +1,13 @@
 //! ref <https://emanual.robotis.com/docs/en/dxl/protocol2>
 
-use crate::protocol::{Controller, Error, Instruction, Protocol, StatusPacket};
-use embedded_hal::{digital::v2::OutputPin, serial};
+use crate::protocol::{
+    resync, Controller, Decode, Echo, Error, Frame, Instruction, Protocol, Retry, RetryCounters,
+    StatusPacket, StatusReturnLevel,
+};
+use core::convert::TryFrom;
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+use embedded_io::{ErrorType, Read, ReadReady, Write};
 use heapless::Vec;
-use nb::block;
 
 const HEADER: [u8; 4] = [0xFF, 0xFF, 0xFD, 0x00];
 
@@ -37,13 +41,182 @@ impl From<u8> for Status {
     }
 }
 
-impl<Serial, Direction> Protocol<Serial, 2> for Controller<Serial, Direction, 2>
+/// Build the Instruction Packet for these params
+pub fn frame<Serial: ErrorType>(
+    id: u8,
+    instruction: Instruction,
+    params: &[u8],
+) -> Result<Frame<'_>, Error<Serial>> {
+    let length = u16::try_from(params.len() + 3)
+        .map_err(|_| Error::TooManyParams)?
+        .to_le_bytes();
+    let content = [id, length[0], length[1], instruction as u8];
+    let mut crc = crc16::State::<crc16::BUYPASS>::new();
+    crc.update(&HEADER);
+    crc.update(&content);
+    crc.update(params);
+    let mut head = Vec::new();
+    head.extend_from_slice(&HEADER)
+        .and_then(|()| head.extend_from_slice(&content))
+        .map_err(|()| Error::TooSmall)?;
+    let mut tail = Vec::new();
+    tail.extend_from_slice(&crc.get().to_le_bytes())
+        .map_err(|()| Error::TooSmall)?;
+    Ok(Frame { head, params, tail })
+}
+
+/// Parser of Protocol 2 Status Packets
+#[derive(Debug, Clone)]
+pub struct Decoder<const MAX_PARAMS_SIZE: usize> {
+    head: usize,
+    discarded: usize, // bytes before the header
+    position: usize,  // after the header
+    packet_id: u8,
+    length: [u8; 2],
+    instruction: u8,
+    error: u8,
+    params: Vec<u8, MAX_PARAMS_SIZE>,
+    crc: [u8; 2],
+}
+
+impl<const MAX_PARAMS_SIZE: usize> Decoder<MAX_PARAMS_SIZE> {
+    pub const fn new() -> Self {
+        Self {
+            head: 0,
+            discarded: 0,
+            position: 0,
+            packet_id: 0,
+            length: [0; 2],
+            instruction: 0,
+            error: 0,
+            params: Vec::new(),
+            crc: [0; 2],
+        }
+    }
+}
+
+impl<const MAX_PARAMS_SIZE: usize> Default for Decoder<MAX_PARAMS_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const MAX_PARAMS_SIZE: usize> Decode<MAX_PARAMS_SIZE> for Decoder<MAX_PARAMS_SIZE> {
+    fn in_header(&self) -> bool {
+        self.head < HEADER.len()
+    }
+
+    fn discarded(&self) -> usize {
+        self.discarded
+    }
+
+    fn frame(&self) -> Frame<'_> {
+        let content = [
+            self.packet_id,
+            self.length[0],
+            self.length[1],
+            self.instruction,
+            self.error,
+        ];
+        let length = usize::from(u16::from_le_bytes(self.length)).saturating_sub(4);
+        let crc = self.position.saturating_sub(5 + length).min(2);
+        let mut head = Vec::new();
+        head.extend_from_slice(&HEADER).ok();
+        head.extend_from_slice(&content[..self.position.min(5)])
+            .ok();
+        Frame {
+            head,
+            params: &self.params,
+            tail: Vec::from_slice(&self.crc[..crc]).unwrap_or_default(),
+        }
+    }
+
+    fn push<Serial: ErrorType>(
+        &mut self,
+        byte: u8,
+    ) -> Option<Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>>> {
+        // wait for HEADER
+        if self.in_header() {
+            if byte == HEADER[self.head] {
+                self.head += 1;
+            } else {
+                let head = resync(&HEADER, self.head, byte);
+                self.discarded += self.head + 1 - head;
+                self.head = head;
+            }
+            return None;
+        }
+
+        // read content
+        let length = usize::from(u16::from_le_bytes(self.length)).saturating_sub(4); // inst + err + crc1 + crc2: 4 bytes.
+        self.position += 1;
+        match self.position {
+            1 => self.packet_id = byte,
+            2 => self.length[0] = byte,
+            3 => self.length[1] = byte,
+            4 => {
+                self.instruction = byte;
+                if byte != Instruction::StatusReturn as u8 {
+                    return Some(Err(Error::InstructionReceived { id: self.packet_id }));
+                }
+            }
+            5 => self.error = byte,
+            p if p <= 5 + length => {
+                if self.params.push(byte).is_err() {
+                    return Some(Err(Error::TooLong { id: self.packet_id }));
+                }
+            }
+            p if p == 6 + length => self.crc[0] = byte,
+            _ => {
+                self.crc[1] = byte;
+                let mut crc = crc16::State::<crc16::BUYPASS>::new();
+                crc.update(&HEADER);
+                crc.update(&[
+                    self.packet_id,
+                    self.length[0],
+                    self.length[1],
+                    self.instruction,
+                    self.error,
+                ]);
+                crc.update(&self.params);
+
+                return Some(if crc.get().to_le_bytes() == self.crc {
+                    Ok(StatusPacket {
+                        packet_id: self.packet_id,
+                        length,
+                        instruction: Some(self.instruction.into()),
+                        error: self.error,
+                        params: self.params.clone(),
+                    })
+                } else {
+                    Err(Error::CrcError { id: self.packet_id })
+                });
+            }
+        }
+        None
+    }
+}
+
+impl<Serial, Direction, Delay> Protocol<Serial, 2> for Controller<Serial, Direction, 2, Delay>
 where
-    Serial: serial::Write<u8> + serial::Read<u8>,
+    Serial: Read + ReadReady + Write,
     Direction: OutputPin,
+    Delay: DelayNs,
 {
-    fn n_recv(&self) -> u8 {
-        self.n_recv
+    fn status_return_level(&self, id: u8) -> StatusReturnLevel {
+        self.status_return_level_of(id)
+    }
+    fn set_status_return_level(&mut self, id: u8, level: StatusReturnLevel) {
+        self.set_status_return_level_of(id, level);
+    }
+    fn retry_policy(&self) -> Retry {
+        self.retry
+    }
+    fn retry_counters(&mut self) -> &mut RetryCounters {
+        &mut self.retry_counters
+    }
+    fn backoff(&mut self) {
+        self.delay.delay_us(self.retry.backoff);
     }
     fn send<const MAX_PARAMS_SIZE: usize>(
         &mut self,
@@ -51,94 +224,121 @@ where
         instruction: Instruction,
         params: Vec<u8, MAX_PARAMS_SIZE>,
     ) -> Result<(), Error<Serial>> {
-        let length = (params.len() + 3).to_le_bytes();
-        let content = [id, length[0], length[1], instruction as u8];
-        let mut crc = crc16::State::<crc16::BUYPASS>::new();
-        crc.update(&HEADER);
-        crc.update(&content);
-        crc.update(params.as_slice());
-        let crc = crc.get().to_le_bytes();
-        let crc = [crc[0], crc[1]];
+        let frame = frame(id, instruction, &params)?;
 
         // send data in half duplex
-        self.direction.set_high().ok();
-        for &b in HEADER
-            .iter()
-            .chain(&content)
-            .chain(params.as_slice())
-            .chain(&crc)
-        {
-            block!(self.serial.write(b)).ok();
-        }
-        block!(self.serial.flush()).ok();
-        self.direction.set_low().ok();
-        Ok(())
+        self.transmit(id, instruction, &frame)
     }
 
     fn recv<const MAX_PARAMS_SIZE: usize>(
         &mut self,
     ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>> {
-        // wait for HEADER
-        let mut head = 0;
-        loop {
-            match block!(self.serial.read()) {
-                Ok(b) if b == HEADER[head] => head += 1,
-                _ => head = 0,
-            }
-            if head == HEADER.len() {
-                break;
-            }
-        }
+        self.receive(Decoder::new())
+    }
+}
 
-        // read content
-        let packet_id: u8 = block!(self.serial.read()).map_err(Error::Communication)?;
-        let length_l: u8 = block!(self.serial.read()).map_err(Error::Communication)?;
-        let length_h: u8 = block!(self.serial.read()).map_err(Error::Communication)?;
-        let instruction: u8 = block!(self.serial.read()).map_err(Error::Communication)?;
-        if instruction != Instruction::StatusReturn as u8 {
-            return Err(Error::InstructionReceived);
-        }
-        let length = u16::from_le_bytes([length_l, length_h]);
-        let length = usize::from(length) - 4; // inst + err + crc1 + crc2: 4 bytes.
-        let error: u8 = block!(self.serial.read()).map_err(Error::Communication)?;
-        let mut params = Vec::new();
-        for _ in 0..length {
-            let byte = block!(self.serial.read()).map_err(Error::Communication)?;
-            params.push(byte).map_err(|_| Error::TooSmall)?;
-        }
-        let mut crcs = [0; 2];
-        crcs[0] = block!(self.serial.read()).map_err(Error::Communication)?;
-        crcs[1] = block!(self.serial.read()).map_err(Error::Communication)?;
-
-        // compute CRC
-        let mut crc = crc16::State::<crc16::BUYPASS>::new();
-        crc.update(&HEADER);
-        crc.update(&[packet_id, length_l, length_h, instruction, error]);
-        for &param in &params {
-            crc.update(&[param]);
-        }
-        let instruction = Some(instruction.into());
-
-        if crc.get().to_le_bytes() == crcs {
-            Ok(StatusPacket {
-                packet_id,
-                length,
-                instruction,
-                error,
-                params,
-            })
-        } else {
-            Err(Error::CrcError)
-        }
+impl<Serial, Direction> Controller<Serial, Direction, 2> {
+    pub const fn new_2(serial: Serial, direction: Direction, echo: Echo) -> Self {
+        Self::new(serial, direction, echo)
     }
 }
 
-impl<Serial, Direction> Controller<Serial, Direction, 2>
-where
-    Serial: serial::Write<u8> + serial::Read<u8>,
-    Direction: OutputPin,
-{
-    pub const fn new_2(serial: Serial, direction: Direction, n_recv: u8) -> Self {
-        Self::new(serial, direction, n_recv)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Serial = &'static [u8];
+
+    /// A Status Packet: an Instruction Packet of StatusReturn, whose first param is the error
+    fn status(id: u8, error: u8, params: &[u8]) -> Vec<u8, 32> {
+        let mut content = Vec::<u8, 16>::new();
+        content.push(error).unwrap();
+        content.extend_from_slice(params).unwrap();
+        let frame = frame::<Serial>(id, Instruction::StatusReturn, &content).unwrap();
+        frame.iter().copied().collect()
+    }
+
+    fn decode<const N: usize>(
+        decoder: &mut Decoder<N>,
+        bytes: &[u8],
+    ) -> Option<Result<StatusPacket<N>, Error<Serial>>> {
+        bytes.iter().find_map(|&b| decoder.push(b))
+    }
+
+    #[test]
+    fn status_packet() {
+        let mut decoder = Decoder::<4>::new();
+        let packet = decode(&mut decoder, &status(1, 0, &[0x20, 0x03]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(packet.packet_id, 1);
+        assert_eq!(packet.params, [0x20, 0x03]);
+        assert_eq!(packet.error_code(), None);
+        assert_eq!(decoder.discarded(), 0);
+    }
+
+    #[test]
+    fn error_without_alert() {
+        let mut decoder = Decoder::<4>::new();
+        let packet = decode(&mut decoder, &status(1, 0x84, &[]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(packet.error_code(), Some(0x04));
+        let packet = decode(&mut Decoder::<4>::new(), &status(1, 0x80, &[]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(packet.error_code(), None);
+    }
+
+    #[test]
+    fn resync_after_noise() {
+        let mut bytes = Vec::<u8, 40>::from_slice(&[0x12, 0xFF, 0xFF, 0x00]).unwrap();
+        bytes.extend_from_slice(&status(2, 0, &[7])).unwrap();
+        let mut decoder = Decoder::<4>::new();
+        let packet = decode(&mut decoder, &bytes).unwrap().unwrap();
+        assert_eq!((packet.packet_id, &packet.params[..]), (2, &[7][..]));
+        assert_eq!(decoder.discarded(), 4);
+    }
+
+    #[test]
+    fn resync_on_repeated_header_byte() {
+        let mut bytes = Vec::<u8, 40>::from_slice(&[0xFF]).unwrap();
+        bytes.extend_from_slice(&status(3, 0, &[])).unwrap();
+        let mut decoder = Decoder::<4>::new();
+        let packet = decode(&mut decoder, &bytes).unwrap().unwrap();
+        assert_eq!(packet.packet_id, 3);
+        assert_eq!(decoder.discarded(), 1);
+    }
+
+    #[test]
+    fn crc_error() {
+        let mut bytes = status(4, 0, &[1, 2]);
+        *bytes.last_mut().unwrap() ^= 0xFF;
+        let result = decode(&mut Decoder::<4>::new(), &bytes).unwrap();
+        assert!(matches!(result, Err(Error::CrcError { id: 4 })));
+    }
+
+    #[test]
+    fn instruction_received() {
+        let frame = frame::<Serial>(5, Instruction::Ping, &[]).unwrap();
+        let bytes: Vec<u8, 16> = frame.iter().copied().collect();
+        let result = decode(&mut Decoder::<4>::new(), &bytes).unwrap();
+        assert!(matches!(result, Err(Error::InstructionReceived { id: 5 })));
+    }
+
+    #[test]
+    fn too_long() {
+        let result = decode(&mut Decoder::<1>::new(), &status(6, 0, &[1, 2])).unwrap();
+        assert!(matches!(result, Err(Error::TooLong { id: 6 })));
+    }
+
+    #[test]
+    fn truncated_frame() {
+        let bytes = status(7, 0, &[1, 2]);
+        let mut decoder = Decoder::<4>::new();
+        assert!(decode(&mut decoder, &bytes[..7]).is_none());
+        assert!(decoder.frame().iter().eq(&bytes[..7]));
+        assert!(decode(&mut decoder, &bytes[7..]).is_some());
+        assert!(decoder.frame().iter().eq(&bytes[..]));
     }
 }
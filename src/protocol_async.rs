@@ -0,0 +1,608 @@
+//! Async counterpart of [`Protocol`](crate::protocol::Protocol), for
+//! [`embedded_io_async`] transports and [`embedded_hal_async`] delays
+//!
+//! Reads race against the delay, so a timed [`Timing`](crate::protocol::Timing) needs a real
+//! delay: [`NoDelay`](crate::protocol::NoDelay) would time out right away.
+
+use crate::control_table::{Model, Register};
+use crate::protocol::{
+    answers, bulk_read_params, bulk_write_params, check, foreign, read_params, size,
+    sync_read_params, sync_write_params, values, values_vec, write_params, Backup, Controller,
+    Decode, Echo, Error, FactoryReset, Frame, Instruction, Release, Retry, RetryCounters,
+    StatusPacket, StatusReturnLevel, BACKUP_KEY, BROADCAST_ID, CLEAR_MULTI_TURN, WRITE_BUFFER_SIZE,
+};
+use crate::{protocol_1, protocol_2};
+use embassy_futures::select::{select, Either};
+use embedded_hal::digital::OutputPin;
+use embedded_hal_async::delay::DelayNs;
+use embedded_io_async::{Read, Write};
+use heapless::Vec;
+
+#[allow(async_fn_in_trait)]
+pub trait AsyncProtocol<Serial, const PROTOCOL_VERSION: u8 = 4>
+where
+    Serial: Read + Write,
+{
+    async fn send<const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        instruction: Instruction,
+        params: Vec<u8, MAX_PARAMS_SIZE>,
+    ) -> Result<(), Error<Serial>>;
+    async fn recv<const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+    ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>>;
+    fn status_return_level(&self, id: u8) -> StatusReturnLevel;
+    /// Remember the status return level of a device, without writing it there
+    fn set_status_return_level(&mut self, id: u8, level: StatusReturnLevel);
+    fn retry_policy(&self) -> Retry;
+    fn retry_counters(&mut self) -> &mut RetryCounters;
+    /// Wait before another attempt
+    async fn backoff(&mut self);
+
+    /// Whether device `id` will answer to this instruction
+    fn returns_status(&self, id: u8, instruction: Instruction) -> bool {
+        id != BROADCAST_ID && self.status_return_level(id).returns_status(instruction)
+    }
+
+    /// Whether to make another attempt after this error, and count it
+    fn retry_after(&mut self, write: bool, attempt: u8, error: &Error<Serial>) -> bool {
+        let retry = self.retry_policy();
+        let again = attempt < retry.attempts && (!write || retry.writes) && retry.retryable(error);
+        if again {
+            let counters = self.retry_counters();
+            counters.retries = counters.retries.wrapping_add(1);
+        }
+        again
+    }
+
+    /// Count the outcome of an operation which took `attempt` attempts
+    fn retry_done(&mut self, attempt: u8, ok: bool) {
+        if attempt > 1 {
+            let counters = self.retry_counters();
+            if ok {
+                counters.recovered = counters.recovered.wrapping_add(1);
+            } else {
+                counters.failed = counters.failed.wrapping_add(1);
+            }
+        }
+    }
+
+    /// Receive the status of device `id`, dropping stale ones from other devices,
+    /// e.g. late answers to a request which timed out
+    ///
+    /// Broken packets from other devices, and instruction packets, are dropped too:
+    /// only errors of the status of device `id` are returned. Any device answers
+    /// `BROADCAST_ID`, e.g. a broadcast ping.
+    async fn recv_from<const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+    ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>> {
+        loop {
+            match self.recv().await {
+                Ok(packet) if !answers(id, packet.packet_id) => {}
+                Err(e) if foreign(id, &e) => {}
+                result => return result,
+            }
+        }
+    }
+
+    /// Ping device `id` once, without retries
+    async fn ping_once(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        self.send(id, Instruction::Ping, Vec::<u8, 0>::new())
+            .await?;
+        self.recv_from::<3>(id).await.map(drop)
+    }
+
+    /// Whether device `id` answers: false if it does not in time, an error if the bus fails
+    async fn ping(&mut self, id: u8) -> Result<bool, Error<Serial>> {
+        let mut attempt = 1;
+        let result = loop {
+            match self.ping_once(id).await {
+                Err(e) if self.retry_after(false, attempt, &e) => self.backoff().await,
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        match result {
+            Ok(()) => Ok(true),
+            Err(Error::Timeout) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Read once, without retries
+    async fn read_once<const SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+    ) -> Result<[u8; SIZE], Error<Serial>> {
+        if !self.returns_status(id, Instruction::Read) {
+            return Err(Error::NoStatus);
+        }
+        let content = read_params::<_, PROTOCOL_VERSION>(address, size(SIZE)?)?;
+        self.send(id, Instruction::Read, content).await?;
+        values(check(id, self.recv_from::<SIZE>(id).await?)?)
+    }
+
+    /// Read SIZE bytes from the control table of device `id`, starting at `address`
+    async fn read<const SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+    ) -> Result<[u8; SIZE], Error<Serial>> {
+        let mut attempt = 1;
+        let result = loop {
+            match self.read_once(id, address).await {
+                Err(e) if self.retry_after(false, attempt, &e) => self.backoff().await,
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        result
+    }
+
+    /// Read `length` bytes once, without retries
+    async fn read_vec_once<const MAX_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        length: u16,
+    ) -> Result<Vec<u8, MAX_SIZE>, Error<Serial>> {
+        if !self.returns_status(id, Instruction::Read) {
+            return Err(Error::NoStatus);
+        }
+        if usize::from(length) > MAX_SIZE {
+            return Err(Error::TooSmall);
+        }
+        let content = read_params::<_, PROTOCOL_VERSION>(address, length)?;
+        self.send(id, Instruction::Read, content).await?;
+        values_vec(check(id, self.recv_from::<MAX_SIZE>(id).await?)?, length)
+    }
+
+    /// Read `length` bytes, up to MAX_SIZE, from the control table of device `id`,
+    /// starting at `address`
+    async fn read_vec<const MAX_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        length: u16,
+    ) -> Result<Vec<u8, MAX_SIZE>, Error<Serial>> {
+        let mut attempt = 1;
+        let result = loop {
+            match self.read_vec_once(id, address, length).await {
+                Err(e) if self.retry_after(false, attempt, &e) => self.backoff().await,
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        result
+    }
+
+    /// Write once, without retries
+    async fn write_once<const PARAMS_SIZE: usize, const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        data: &[u8],
+    ) -> Result<Option<StatusPacket<MAX_PARAMS_SIZE>>, Error<Serial>> {
+        let content = write_params::<_, PROTOCOL_VERSION, PARAMS_SIZE>(address, data)?;
+        self.send(id, Instruction::Write, content).await?;
+        if self.returns_status(id, Instruction::Write) {
+            Ok(Some(check(id, self.recv_from(id).await?)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Write `data` in the control table of device `id`, starting at `address`
+    ///
+    /// PARAMS_SIZE must hold the address and the data.
+    /// The Status Packet is received only if the device is expected to send one.
+    /// This is retried only if the retry policy allows writes.
+    async fn write<const PARAMS_SIZE: usize, const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        data: &[u8],
+    ) -> Result<Option<StatusPacket<MAX_PARAMS_SIZE>>, Error<Serial>> {
+        let mut attempt = 1;
+        let result = loop {
+            match self
+                .write_once::<PARAMS_SIZE, MAX_PARAMS_SIZE>(id, address, data)
+                .await
+            {
+                Err(e) if self.retry_after(true, attempt, &e) => self.backoff().await,
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        result
+    }
+
+    /// Write SIZE bytes at `address` on several devices at once
+    async fn sync_write<const PARAMS_SIZE: usize, const SIZE: usize>(
+        &mut self,
+        address: u16,
+        data: &[(u8, [u8; SIZE])],
+    ) -> Result<(), Error<Serial>> {
+        let content = sync_write_params::<_, PROTOCOL_VERSION, PARAMS_SIZE, SIZE>(address, data)?;
+        self.send(BROADCAST_ID, Instruction::SyncWrite, content)
+            .await
+    }
+
+    /// Read SIZE bytes at `address` from several devices at once (Protocol 2 only)
+    ///
+    /// Devices which do not return status for reads are skipped.
+    async fn sync_read<const PARAMS_SIZE: usize, const SIZE: usize, const N: usize>(
+        &mut self,
+        address: u16,
+        ids: &[u8],
+    ) -> Result<Vec<(u8, [u8; SIZE]), N>, Error<Serial>> {
+        let content = sync_read_params::<_, PARAMS_SIZE>(address, size(SIZE)?, ids)?;
+        self.send(BROADCAST_ID, Instruction::SyncRead, content)
+            .await?;
+        let mut read = Vec::new();
+        for &id in ids {
+            if self.returns_status(id, Instruction::SyncRead) {
+                let value = values(check(id, self.recv_from::<SIZE>(id).await?)?)?;
+                read.push((id, value)).map_err(|_| Error::TooSmall)?;
+            }
+        }
+        Ok(read)
+    }
+
+    /// Read (id, address, size) from several devices at once
+    ///
+    /// Devices which do not return status for reads are skipped.
+    async fn bulk_read<const PARAMS_SIZE: usize, const MAX_PARAMS_SIZE: usize, const N: usize>(
+        &mut self,
+        reads: &[(u8, u16, u16)],
+    ) -> Result<Vec<StatusPacket<MAX_PARAMS_SIZE>, N>, Error<Serial>> {
+        let content = bulk_read_params::<_, PROTOCOL_VERSION, PARAMS_SIZE>(reads)?;
+        self.send(BROADCAST_ID, Instruction::BulkRead, content)
+            .await?;
+        let mut packets = Vec::new();
+        for &(id, _, _) in reads {
+            if self.returns_status(id, Instruction::BulkRead) {
+                let packet = check(id, self.recv_from(id).await?)?;
+                packets.push(packet).map_err(|_| Error::TooSmall)?;
+            }
+        }
+        Ok(packets)
+    }
+
+    /// Write (id, address, data) on several devices at once (Protocol 2 only)
+    async fn bulk_write<const PARAMS_SIZE: usize>(
+        &mut self,
+        writes: &[(u8, u16, &[u8])],
+    ) -> Result<(), Error<Serial>> {
+        let content = bulk_write_params::<_, PARAMS_SIZE>(writes)?;
+        self.send(BROADCAST_ID, Instruction::BulkWrite, content)
+            .await
+    }
+
+    /// Send an instruction, and check its status if device `id` returns one
+    async fn command<const PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        instruction: Instruction,
+        params: Vec<u8, PARAMS_SIZE>,
+    ) -> Result<(), Error<Serial>> {
+        self.send(id, instruction, params).await?;
+        if self.returns_status(id, instruction) {
+            check(id, self.recv_from::<0>(id).await?)?;
+        }
+        Ok(())
+    }
+
+    /// Reboot device `id`, whose status may never come if it restarts first
+    async fn reboot(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        match self
+            .command(id, Instruction::Reboot, Vec::<u8, 0>::new())
+            .await
+        {
+            Err(Error::Timeout) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Reset the control table of device `id` to its factory settings
+    ///
+    /// Protocol 1 only resets everything, including the ID and the baud rate.
+    async fn factory_reset(&mut self, id: u8, mode: FactoryReset) -> Result<(), Error<Serial>> {
+        let mut params = Vec::<u8, 1>::new();
+        if PROTOCOL_VERSION == 1 {
+            if mode != FactoryReset::All {
+                return Err(Error::Unsupported);
+            }
+        } else {
+            params.push(mode as u8).map_err(|_| Error::TooSmall)?;
+        }
+        self.command(id, Instruction::FactoryReset, params).await
+    }
+
+    /// Reset the multi-turn position of device `id` to within a turn (Protocol 2 only)
+    async fn clear_multi_turn(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        if PROTOCOL_VERSION == 1 {
+            return Err(Error::Unsupported);
+        }
+        let params = Vec::<u8, 5>::from_slice(&CLEAR_MULTI_TURN).map_err(|()| Error::TooSmall)?;
+        self.command(id, Instruction::Clear, params).await
+    }
+
+    /// Store the control table of device `id` to its backup area, or restore it from there
+    /// (Protocol 2 only)
+    async fn control_table_backup(
+        &mut self,
+        id: u8,
+        operation: Backup,
+    ) -> Result<(), Error<Serial>> {
+        if PROTOCOL_VERSION == 1 {
+            return Err(Error::Unsupported);
+        }
+        let mut params = Vec::<u8, 5>::new();
+        params
+            .push(operation as u8)
+            .map_err(|_| Error::TooSmall)
+            .and_then(|()| {
+                params
+                    .extend_from_slice(&BACKUP_KEY)
+                    .map_err(|()| Error::TooSmall)
+            })?;
+        self.command(id, Instruction::ControlTableBackup, params)
+            .await
+    }
+
+    /// Register a write of `data` at `address` on device `id`, done on the next Action
+    ///
+    /// PARAMS_SIZE must hold the address and the data.
+    /// This is retried only if the retry policy allows writes.
+    async fn reg_write<const PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        address: u16,
+        data: &[u8],
+    ) -> Result<(), Error<Serial>> {
+        let params = write_params::<_, PROTOCOL_VERSION, PARAMS_SIZE>(address, data)?;
+        let mut attempt = 1;
+        let result = loop {
+            match self
+                .command(id, Instruction::RegWrite, params.clone())
+                .await
+            {
+                Err(e) if self.retry_after(true, attempt, &e) => self.backoff().await,
+                result => break result,
+            }
+            attempt += 1;
+        };
+        self.retry_done(attempt, result.is_ok());
+        result
+    }
+
+    /// Register a write of `value` in `register` of device `id`, done on the next Action
+    async fn stage(
+        &mut self,
+        id: u8,
+        register: &Register,
+        value: u32,
+    ) -> Result<(), Error<Serial>> {
+        let size = usize::from(register.size).min(4);
+        self.reg_write::<6>(id, register.address, &value.to_le_bytes()[..size])
+            .await
+    }
+
+    /// Do the writes registered on device `id`, or on every device with [`BROADCAST_ID`]
+    async fn action(&mut self, id: u8) -> Result<(), Error<Serial>> {
+        self.command(id, Instruction::Action, Vec::<u8, 0>::new())
+            .await
+    }
+
+    /// Whether device `id`, of this model, has a registered write waiting for an Action
+    async fn registered(&mut self, id: u8, model: &Model) -> Result<bool, Error<Serial>> {
+        let register = model
+            .register("registered_instruction")
+            .or_else(|| model.register("registered"))
+            .ok_or(Error::Unsupported)?;
+        Ok(self.read::<1>(id, register.address).await? != [0])
+    }
+}
+
+impl<Serial, Direction, const PROTOCOL_VERSION: u8, Delay>
+    Controller<Serial, Direction, PROTOCOL_VERSION, Delay>
+where
+    Serial: Read + Write,
+    Direction: OutputPin,
+    Delay: DelayNs,
+{
+    /// Send bytes in half duplex, and drop their echo if needed
+    async fn transmit_async(
+        &mut self,
+        id: u8,
+        instruction: Instruction,
+        frame: &Frame<'_>,
+    ) -> Result<(), Error<Serial>> {
+        self.trace_sent(id, instruction, frame);
+        let packet = frame.iter();
+        self.delay.delay_us(self.timing.guard).await;
+        self.direction.set_high().ok();
+        // a single write rather than one per byte, not to stretch the packet on the wire
+        let mut buffer = Vec::<u8, WRITE_BUFFER_SIZE>::new();
+        let mut sent = Ok(());
+        for slice in frame.slices(&mut buffer) {
+            sent = self.serial.write_all(slice).await;
+            if sent.is_err() {
+                break;
+            }
+        }
+        if sent.is_ok() {
+            sent = self.serial.flush().await;
+        }
+        match self.timing.release {
+            Release::Flushed => {}
+            Release::LastByte => self.delay.delay_us(self.timing.byte()).await,
+            Release::Hook(complete) => {
+                let timeout = self.timing.byte_timeout();
+                let mut waited = 0;
+                while !complete() && timeout.is_none_or(|timeout| waited < timeout) {
+                    self.delay.delay_us(1).await;
+                    waited += 1;
+                }
+            }
+        }
+        self.delay.delay_us(self.timing.turnaround).await;
+        self.direction.set_low().ok();
+        sent.map_err(Error::Communication)?;
+        self.sent(id, instruction);
+
+        // drop our own packet, but check it on the way
+        if self.echo == Echo::Discard {
+            let mut mismatch = false;
+            let mut timeout = self.timing.first_byte_timeout();
+            for &b in packet {
+                mismatch |= self.read_byte_async(timeout).await? != b;
+                timeout = self.timing.byte_timeout();
+            }
+            if mismatch {
+                return Err(Error::EchoMismatch);
+            }
+        }
+        Ok(())
+    }
+
+    /// Wait for a byte, at most `timeout` µs if any
+    async fn read_byte_async(&mut self, timeout: Option<u32>) -> Result<u8, Error<Serial>> {
+        let mut byte = [0];
+        let read = match timeout {
+            Some(timeout) => {
+                match select(self.serial.read(&mut byte), self.delay.delay_us(timeout)).await {
+                    Either::First(read) => read,
+                    Either::Second(()) => return Err(Error::Timeout),
+                }
+            }
+            None => self.serial.read(&mut byte).await,
+        };
+        match read.map_err(Error::Communication)? {
+            0 => Err(Error::Timeout),
+            _ => Ok(byte[0]),
+        }
+    }
+
+    /// Feed received bytes to `decoder`, until it has a complete packet
+    async fn receive_async<D, const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        mut decoder: D,
+    ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>>
+    where
+        D: Decode<MAX_PARAMS_SIZE>,
+    {
+        let mut timeout = self.timing.first_byte_timeout();
+        let mut skipped = 0;
+        let result = loop {
+            let byte = match self.read_byte_async(timeout).await {
+                Ok(byte) => byte,
+                Err(Error::Communication(e)) if decoder.in_header() => {
+                    // skip glitches, but not an error which persists
+                    skipped += 1;
+                    if self.timing.skipped_too_long(skipped) {
+                        break Err(Error::Communication(e));
+                    }
+                    self.delay.delay_us(self.timing.byte()).await;
+                    continue;
+                }
+                Err(e) => break Err(e),
+            };
+            timeout = self.timing.byte_timeout();
+            if let Some(packet) = decoder.push(byte) {
+                break packet;
+            }
+        };
+        match &result {
+            Ok(packet) => self.trace_received(packet, &decoder.frame()),
+            Err(e) if !decoder.in_header() => self.trace_broken(e, &decoder.frame()),
+            Err(_) => {}
+        }
+        self.received(decoder.discarded(), &result);
+        result
+    }
+}
+
+impl<Serial, Direction, Delay> AsyncProtocol<Serial, 1> for Controller<Serial, Direction, 1, Delay>
+where
+    Serial: Read + Write,
+    Direction: OutputPin,
+    Delay: DelayNs,
+{
+    fn status_return_level(&self, id: u8) -> StatusReturnLevel {
+        self.status_return_level_of(id)
+    }
+    fn set_status_return_level(&mut self, id: u8, level: StatusReturnLevel) {
+        self.set_status_return_level_of(id, level);
+    }
+    fn retry_policy(&self) -> Retry {
+        self.retry
+    }
+    fn retry_counters(&mut self) -> &mut RetryCounters {
+        &mut self.retry_counters
+    }
+    async fn backoff(&mut self) {
+        self.delay.delay_us(self.retry.backoff).await;
+    }
+    async fn send<const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        instruction: Instruction,
+        params: Vec<u8, MAX_PARAMS_SIZE>,
+    ) -> Result<(), Error<Serial>> {
+        let frame = protocol_1::frame(id, instruction, &params)?;
+        self.transmit_async(id, instruction, &frame).await
+    }
+
+    async fn recv<const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+    ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>> {
+        self.receive_async(protocol_1::Decoder::new()).await
+    }
+}
+
+impl<Serial, Direction, Delay> AsyncProtocol<Serial, 2> for Controller<Serial, Direction, 2, Delay>
+where
+    Serial: Read + Write,
+    Direction: OutputPin,
+    Delay: DelayNs,
+{
+    fn status_return_level(&self, id: u8) -> StatusReturnLevel {
+        self.status_return_level_of(id)
+    }
+    fn set_status_return_level(&mut self, id: u8, level: StatusReturnLevel) {
+        self.set_status_return_level_of(id, level);
+    }
+    fn retry_policy(&self) -> Retry {
+        self.retry
+    }
+    fn retry_counters(&mut self) -> &mut RetryCounters {
+        &mut self.retry_counters
+    }
+    async fn backoff(&mut self) {
+        self.delay.delay_us(self.retry.backoff).await;
+    }
+    async fn send<const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+        id: u8,
+        instruction: Instruction,
+        params: Vec<u8, MAX_PARAMS_SIZE>,
+    ) -> Result<(), Error<Serial>> {
+        let frame = protocol_2::frame(id, instruction, &params)?;
+        self.transmit_async(id, instruction, &frame).await
+    }
+
+    async fn recv<const MAX_PARAMS_SIZE: usize>(
+        &mut self,
+    ) -> Result<StatusPacket<MAX_PARAMS_SIZE>, Error<Serial>> {
+        self.receive_async(protocol_2::Decoder::new()).await
+    }
+}
@@ -1,10 +1,20 @@
 //! Implementation of [`Serial`](https://docs.rs/embedded-hal/0.2.5/embedded_hal/serial/index.html)
-//! for [`SerialPort`](https://docs.rs/serialport/4.0.1/serialport/trait.SerialPort.html)
+//! and of [`embedded_io`] `Read` / `ReadReady` / `Write`
+//! for [`SerialPort`](https://docs.rs/serialport/4.0.1/serialport/trait.SerialPort.html),
+//! and of [`DelayNs`](https://docs.rs/embedded-hal/1.0.0/embedded_hal/delay/trait.DelayNs.html)
+//! for [`std::thread::sleep`]
+//!
+//! Written bytes are buffered until `flush`, so a whole packet goes out in a single syscall,
+//! and received bytes are read by chunks into a ring buffer.
 
 extern crate std;
+use crate::{admin, protocol};
+use core::fmt;
 use serialport::SerialPort;
+use std::collections::VecDeque;
 use std::io::{Read, Write};
-use std::println;
+use std::vec::Vec;
+use std::{thread, time};
 
 #[cfg(unix)]
 type Port = serialport::TTYPort;
@@ -12,18 +22,109 @@ type Port = serialport::TTYPort;
 #[cfg(windows)]
 type Port = serialport::COMPort;
 
+/// Size of the chunks read from the port
+const CHUNK: usize = 256;
+
 pub struct Serial {
     port: Port,
+    rx: VecDeque<u8>,
+    tx: Vec<u8>,
 }
 
 impl Serial {
-    pub fn new(port: serialport::SerialPortBuilder) -> Self {
-        Serial {
-            port: Port::open(&port).unwrap(),
+    /// Open the port. Its timeout bounds how long a read waits for the first byte.
+    pub fn new(port: serialport::SerialPortBuilder) -> Result<Self, IoSerialError> {
+        Ok(Serial {
+            port: Port::open(&port)?,
+            rx: VecDeque::with_capacity(CHUNK),
+            tx: Vec::with_capacity(CHUNK),
+        })
+    }
+
+    /// Set how long a read waits for the first byte
+    pub fn set_timeout(&mut self, timeout: time::Duration) -> Result<(), IoSerialError> {
+        Ok(self.port.set_timeout(timeout)?)
+    }
+
+    /// Change the baud rate, e.g. after that of the devices
+    pub fn set_baud_rate(&mut self, baud_rate: u32) -> Result<(), IoSerialError> {
+        Ok(self.port.set_baud_rate(baud_rate)?)
+    }
+
+    /// Ask the driver to push received bytes right away, instead of waiting for its latency timer
+    /// (e.g. 16ms by default on FTDI adapters)
+    #[cfg(target_os = "linux")]
+    pub fn set_low_latency(&mut self, low_latency: bool) -> std::io::Result<()> {
+        use std::os::unix::io::AsRawFd;
+
+        // from linux/serial.h
+        const ASYNC_LOW_LATENCY: libc::c_int = 1 << 13;
+        #[repr(C)]
+        struct SerialStruct {
+            type_: libc::c_int,
+            line: libc::c_int,
+            port: libc::c_uint,
+            irq: libc::c_int,
+            flags: libc::c_int,
+            xmit_fifo_size: libc::c_int,
+            custom_divisor: libc::c_int,
+            baud_base: libc::c_int,
+            close_delay: libc::c_ushort,
+            io_type: libc::c_char,
+            reserved_char: [libc::c_char; 1],
+            hub6: libc::c_int,
+            closing_wait: libc::c_ushort,
+            closing_wait2: libc::c_ushort,
+            iomem_base: *mut libc::c_uchar,
+            iomem_reg_shift: libc::c_ushort,
+            port_high: libc::c_uint,
+            iomap_base: libc::c_ulong,
+        }
+
+        let fd = self.port.as_raw_fd();
+        let mut serial = core::mem::MaybeUninit::<SerialStruct>::zeroed();
+        // SAFETY: TIOCGSERIAL fills a serial_struct, which SerialStruct mirrors
+        if unsafe { libc::ioctl(fd, libc::TIOCGSERIAL, serial.as_mut_ptr()) } < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        // SAFETY: initialized by TIOCGSERIAL
+        let mut serial = unsafe { serial.assume_init() };
+        if low_latency {
+            serial.flags |= ASYNC_LOW_LATENCY;
+        } else {
+            serial.flags &= !ASYNC_LOW_LATENCY;
+        }
+        // SAFETY: TIOCSSERIAL reads the serial_struct we got from TIOCGSERIAL
+        if unsafe { libc::ioctl(fd, libc::TIOCSSERIAL, &serial) } < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// Move up to CHUNK received bytes into the ring buffer.
+    /// If `wait`, block until at least one byte or the timeout, else only take what is there.
+    fn fill(&mut self, wait: bool) -> Result<(), IoSerialError> {
+        if !wait && self.port.bytes_to_read().map_err(IoSerialError::Serial)? == 0 {
+            return Ok(());
         }
+        let mut chunk = [0; CHUNK];
+        let n = self.port.read(&mut chunk)?;
+        self.rx.extend(&chunk[..n]);
+        Ok(())
+    }
+
+    /// Send the buffered bytes in a single write, and wait until they are out
+    fn send(&mut self) -> Result<(), IoSerialError> {
+        if !self.tx.is_empty() {
+            self.port.write_all(&self.tx)?;
+            self.tx.clear();
+        }
+        self.port.flush()?;
+        Ok(())
     }
 }
 
+#[derive(Debug)]
 pub enum IoSerialError {
     Io(std::io::Error),
     Serial(serialport::Error),
@@ -31,49 +132,122 @@ pub enum IoSerialError {
 
 pub type Error = nb::Error<IoSerialError>;
 
-fn from_io(e: std::io::Error) -> Error {
-    nb::Error::Other(IoSerialError::Io(e))
+impl From<std::io::Error> for IoSerialError {
+    fn from(e: std::io::Error) -> Self {
+        IoSerialError::Io(e)
+    }
 }
 
-fn from_serial(e: serialport::Error) -> Error {
-    nb::Error::Other(IoSerialError::Serial(e))
+impl From<serialport::Error> for IoSerialError {
+    fn from(e: serialport::Error) -> Self {
+        IoSerialError::Serial(e)
+    }
 }
 
-impl From<std::io::Error> for IoSerialError {
-    fn from(e: std::io::Error) -> Self {
-        IoSerialError::Io(e)
+impl fmt::Display for IoSerialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IoSerialError::Io(e) => write!(f, "serial I/O error: {e}"),
+            IoSerialError::Serial(e) => write!(f, "serial port error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for IoSerialError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IoSerialError::Io(e) => Some(e),
+            IoSerialError::Serial(e) => Some(e),
+        }
+    }
+}
+
+impl<S: embedded_io::ErrorType<Error = IoSerialError>> From<IoSerialError> for protocol::Error<S> {
+    fn from(e: IoSerialError) -> Self {
+        protocol::Error::Communication(e)
     }
 }
 
-impl embedded_hal::serial::Read<u8> for Serial {
+impl embedded_hal_02::serial::Read<u8> for Serial {
     type Error = IoSerialError;
 
     fn read(&mut self) -> Result<u8, Error> {
-        if self.port.bytes_to_read().map_err(from_serial)? == 0 {
-            return Err(nb::Error::WouldBlock);
+        if self.rx.is_empty() {
+            self.fill(false)?;
         }
-        let mut byte: [u8; 1] = [0];
-        self.port.read(&mut byte).map_err(from_io)?;
-        //println!("SEH read {:?}", byte);
-        Ok(byte[0])
+        self.rx.pop_front().ok_or(nb::Error::WouldBlock)
     }
 }
 
-impl embedded_hal::serial::Write<u8> for Serial {
+impl embedded_hal_02::serial::Write<u8> for Serial {
     type Error = IoSerialError;
 
+    /// The word is sent on `flush`
     fn write(&mut self, word: u8) -> Result<(), Error> {
-        //println!("SEH writing {}", word);
-        while self.port.write(&[word]).map_err(from_io)? != 1 {
-            println!("writing {word} again");
-        }
+        self.tx.push(word);
         Ok(())
     }
     fn flush(&mut self) -> Result<(), Error> {
-        //println!("SEH flushing");
-        if self.port.bytes_to_write().map_err(from_serial)? != 0 {
-            return Err(nb::Error::WouldBlock);
+        self.send().map_err(nb::Error::Other)
+    }
+}
+
+impl embedded_io::Error for IoSerialError {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        match self {
+            IoSerialError::Io(e) => e.kind().into(),
+            IoSerialError::Serial(_) => embedded_io::ErrorKind::Other,
+        }
+    }
+}
+
+impl embedded_io::ErrorType for Serial {
+    type Error = IoSerialError;
+}
+
+impl embedded_io::Read for Serial {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, IoSerialError> {
+        if self.rx.is_empty() {
+            self.fill(true)?;
         }
-        self.port.flush().map_err(from_io)
+        let n = buf.len().min(self.rx.len());
+        for (b, r) in buf.iter_mut().zip(self.rx.drain(..n)) {
+            *b = r;
+        }
+        Ok(n)
+    }
+}
+
+impl embedded_io::ReadReady for Serial {
+    fn read_ready(&mut self) -> Result<bool, IoSerialError> {
+        if self.rx.is_empty() {
+            self.fill(false)?;
+        }
+        Ok(!self.rx.is_empty())
+    }
+}
+
+/// Bytes are sent on `flush`
+impl embedded_io::Write for Serial {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, IoSerialError> {
+        self.tx.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> Result<(), IoSerialError> {
+        self.send()
+    }
+}
+
+impl admin::SetBaudRate for Serial {
+    fn set_baud_rate(&mut self, baud_rate: u32) -> Result<(), IoSerialError> {
+        Serial::set_baud_rate(self, baud_rate)
+    }
+}
+
+pub struct Delay;
+
+impl embedded_hal::delay::DelayNs for Delay {
+    fn delay_ns(&mut self, ns: u32) {
+        thread::sleep(time::Duration::from_nanos(ns.into()));
     }
 }
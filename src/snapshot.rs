@@ -0,0 +1,232 @@
+//! Whole control table of a device, read in a few transactions
+
+use crate::control_table::{Model, Register, Unit};
+use crate::protocol::{Error, Protocol};
+use core::fmt;
+use embedded_io::{Read, ReadReady, Write};
+use heapless::Vec;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Most registers of a generated control table, besides its indirect address table
+pub const MAX_REGISTERS: usize = 96;
+
+/// Most bytes read at once
+const CHUNK: usize = 64;
+
+/// Most unknown bytes read between two registers of a chunk
+const MAX_GAP: u16 = 8;
+
+/// A register, and its value
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Value {
+    pub register: &'static Register,
+    pub raw: u32,
+    pub unit: Option<Unit>,
+}
+
+impl Value {
+    /// Raw value, sign-extended if the register is signed
+    pub fn signed(&self) -> i64 {
+        match self.unit {
+            Some(unit) if unit.signed => {
+                let shift = 64 - 8 * u32::from(self.register.size);
+                (i64::from(self.raw) << shift) >> shift
+            }
+            _ => i64::from(self.raw),
+        }
+    }
+
+    /// Value in its unit, if known
+    pub fn converted(&self) -> Option<f32> {
+        self.unit.map(|unit| self.signed() as f32 * unit.scale)
+    }
+}
+
+/// Values of the registers of a device, in the order of its control table
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub id: u8,
+    pub model: &'static Model,
+    pub values: Vec<Value, MAX_REGISTERS>,
+}
+
+/// Address range of the first registers of `table` which can be read at once, and their count
+///
+/// A chunk ends before a gap, or a register below the first one: control tables are not all
+/// sorted by address.
+fn chunk(table: &[&Register]) -> (u16, u16, usize) {
+    let start = table.first().map_or(0, |first| first.address);
+    let mut end = start;
+    let mut count = 0;
+    for register in table {
+        let register_end = register.address + u16::from(register.size);
+        match register.address.checked_sub(start) {
+            Some(offset)
+                if register.address <= end + MAX_GAP
+                    && usize::from(offset + u16::from(register.size)) <= CHUNK => {}
+            _ => break,
+        }
+        end = end.max(register_end);
+        count += 1;
+    }
+    (start, end, count)
+}
+
+impl Snapshot {
+    /// Read the control table of device `id`, by chunks of consecutive registers
+    ///
+    /// Indirect address tables are left out: see [`indirect`](crate::indirect).
+    pub fn read<P, Serial, const PROTOCOL_VERSION: u8>(
+        dmx: &mut P,
+        id: u8,
+        model: &'static Model,
+    ) -> Result<Self, Error<Serial>>
+    where
+        P: Protocol<Serial, PROTOCOL_VERSION>,
+        Serial: Read + ReadReady + Write,
+    {
+        let mut values = Vec::new();
+        let mut registers = Vec::<&'static Register, MAX_REGISTERS>::new();
+        for register in model.control_table {
+            if !register.name.starts_with("indirect_") {
+                registers.push(register).map_err(|_| Error::TooSmall)?;
+            }
+        }
+        let mut table = &registers[..];
+        while !table.is_empty() {
+            let (start, end, count) = chunk(table);
+            let bytes = dmx.read_vec::<CHUNK>(id, start, end - start)?;
+            for &register in &table[..count] {
+                let offset = usize::from(register.address - start);
+                let mut raw = [0; 4];
+                let size = usize::from(register.size).min(4);
+                raw[..size].copy_from_slice(&bytes[offset..offset + size]);
+                let value = Value {
+                    register,
+                    raw: u32::from_le_bytes(raw),
+                    unit: model.unit(register),
+                };
+                values.push(value).map_err(|_| Error::TooSmall)?;
+            }
+            table = &table[count..];
+        }
+        Ok(Self { id, model, values })
+    }
+
+    /// Value of register `name`
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        let register = self.model.register(name)?;
+        self.values
+            .iter()
+            .find(|value| core::ptr::eq(value.register, register))
+    }
+
+    /// Values as a JSON object, keyed by register name
+    #[cfg(feature = "std")]
+    pub fn to_json(&self) -> std::string::String {
+        use core::fmt::Write;
+        let mut json = std::string::String::new();
+        let _ = write!(
+            json,
+            "{{\"id\": {}, \"model\": \"{}\", \"model_number\": {}, \"registers\": {{",
+            self.id, self.model.name, self.model.model_number
+        );
+        for (i, value) in self.values.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            let _ = write!(
+                json,
+                "{separator}\"{}\": {{\"address\": {}, \"raw\": {}",
+                value.register.name,
+                value.register.address,
+                value.signed()
+            );
+            if let (Some(converted), Some(unit)) = (value.converted(), value.unit) {
+                let _ = write!(
+                    json,
+                    ", \"value\": {converted}, \"unit\": \"{}\"",
+                    unit.symbol
+                );
+            }
+            json.push('}');
+        }
+        json.push_str("}}");
+        json
+    }
+}
+
+/// Aligned table of the values
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} (model {}) ID {}",
+            self.model.name, self.model.model_number, self.id
+        )?;
+        let width = self
+            .values
+            .iter()
+            .map(|value| value.register.name.len())
+            .max()
+            .unwrap_or(0);
+        for value in &self.values {
+            let register = value.register;
+            write!(
+                f,
+                "{:4} {:width$} {:11}",
+                register.address,
+                register.name,
+                value.signed()
+            )?;
+            if let (Some(converted), Some(unit)) = (value.converted(), value.unit) {
+                write!(f, " {converted:.2} {}", unit.symbol)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control_table::MODELS;
+
+    #[test]
+    fn chunks_cover_every_register() {
+        for model in MODELS {
+            let registers: Vec<_, MAX_REGISTERS> = model
+                .control_table
+                .iter()
+                .filter(|register| !register.name.starts_with("indirect_"))
+                .collect();
+            let mut table = &registers[..];
+            while !table.is_empty() {
+                let (start, end, count) = chunk(table);
+                assert!(
+                    count > 0 && usize::from(end - start) <= CHUNK,
+                    "{}",
+                    model.name
+                );
+                for register in &table[..count] {
+                    assert!(register.address >= start, "{}", model.name);
+                    assert!(register.address + u16::from(register.size) <= end);
+                }
+                table = &table[count..];
+            }
+        }
+    }
+
+    #[test]
+    fn chunk_ends_below_its_start() {
+        let model = crate::control_table::model_named("h54200s500ra").unwrap();
+        let p_gain = model.register("position_p_gain").unwrap();
+        let i_gain = model.register("position_i_gain").unwrap();
+        assert!(i_gain.address < p_gain.address);
+        assert_eq!(
+            chunk(&[p_gain, i_gain]),
+            (p_gain.address, p_gain.address + 2, 1)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Bus statistics, to diagnose cable and termination issues
+
+use crate::protocol::Instruction;
+use heapless::Vec;
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+/// Number of instructions of the protocols
+pub const INSTRUCTIONS: usize = 17;
+
+/// Most device IDs whose status errors are counted apart
+pub const STATUS_ERROR_IDS: usize = 16;
+
+/// Counters of a Controller, since it was created or since its last reset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub sent: u32,            // Instruction Packets sent
+    pub received: u32,        // Status Packets received
+    pub crc_errors: u32,      // Status Packets with a wrong checksum
+    pub timeouts: u32,        // Status Packets which did not come in time
+    pub discarded_bytes: u32, // bytes dropped while looking for a header
+    /// Status Packets reporting an error, per ID
+    pub status_errors: Vec<(u8, u32), STATUS_ERROR_IDS>,
+    /// Status Packets reporting an error, from further IDs once `status_errors` is full
+    pub other_status_errors: u32,
+    #[cfg(feature = "std")]
+    pub latency: [Latency; INSTRUCTIONS], // from the end of a request to its first response
+}
+
+impl Stats {
+    pub const fn new() -> Self {
+        Self {
+            sent: 0,
+            received: 0,
+            crc_errors: 0,
+            timeouts: 0,
+            discarded_bytes: 0,
+            status_errors: Vec::new(),
+            other_status_errors: 0,
+            #[cfg(feature = "std")]
+            latency: [Latency::new(); INSTRUCTIONS],
+        }
+    }
+
+    /// Status errors reported by device `id`
+    ///
+    /// This is 0 for IDs beyond the first STATUS_ERROR_IDS to report errors: see
+    /// `other_status_errors`.
+    pub fn status_errors_of(&self, id: u8) -> u32 {
+        self.status_errors
+            .iter()
+            .find(|&&(known, _)| known == id)
+            .map_or(0, |&(_, count)| count)
+    }
+
+    pub(crate) fn status_error(&mut self, id: u8) {
+        match self
+            .status_errors
+            .iter_mut()
+            .find(|(known, _)| *known == id)
+        {
+            Some((_, count)) => *count = count.wrapping_add(1),
+            None => {
+                if self.status_errors.push((id, 1)).is_err() {
+                    self.other_status_errors = self.other_status_errors.wrapping_add(1);
+                }
+            }
+        }
+    }
+
+    /// Round-trip latency of this instruction
+    #[cfg(feature = "std")]
+    pub fn latency_of(&self, instruction: Instruction) -> &Latency {
+        &self.latency[slot(instruction)]
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn record_latency(&mut self, instruction: Instruction, latency: Duration) {
+        self.latency[slot(instruction)].record(latency);
+    }
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Min / average / max of the round trips of an instruction
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Latency {
+    pub count: u32,
+    pub min: Duration,
+    pub max: Duration,
+    pub total: Duration,
+}
+
+#[cfg(feature = "std")]
+impl Latency {
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            min: Duration::MAX,
+            max: Duration::ZERO,
+            total: Duration::ZERO,
+        }
+    }
+
+    pub fn average(&self) -> Option<Duration> {
+        self.total.checked_div(self.count)
+    }
+
+    fn record(&mut self, latency: Duration) {
+        self.count = self.count.wrapping_add(1);
+        self.min = self.min.min(latency);
+        self.max = self.max.max(latency);
+        self.total = self.total.saturating_add(latency);
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for Latency {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Index of an instruction in `Stats::latency`
+pub const fn slot(instruction: Instruction) -> usize {
+    match instruction {
+        Instruction::Ping => 0,
+        Instruction::Read => 1,
+        Instruction::Write => 2,
+        Instruction::RegWrite => 3,
+        Instruction::Action => 4,
+        Instruction::FactoryReset => 5,
+        Instruction::Reboot => 6,
+        Instruction::Clear => 7,
+        Instruction::ControlTableBackup => 8,
+        Instruction::StatusReturn => 9,
+        Instruction::SyncRead => 10,
+        Instruction::SyncWrite => 11,
+        Instruction::FastSyncRead => 12,
+        Instruction::BulkRead => 13,
+        Instruction::BulkWrite => 14,
+        Instruction::FastBulkRead => 15,
+        Instruction::WrongInstruction => 16,
+    }
+}
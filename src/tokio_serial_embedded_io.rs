@@ -0,0 +1,54 @@
+//! Implementation of [`embedded_io_async`] `Read` / `Write`
+//! for [`SerialStream`](https://docs.rs/tokio-serial/5.4.5/tokio_serial/struct.SerialStream.html),
+//! and of [`DelayNs`](https://docs.rs/embedded-hal-async/1.0.0/embedded_hal_async/delay/trait.DelayNs.html)
+//! for [`tokio::time::sleep`]
+
+extern crate std;
+use std::io;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_serial::{SerialPortBuilder, SerialStream};
+
+pub struct Serial {
+    port: SerialStream,
+}
+
+impl Serial {
+    /// Open the port: this must be called from within a tokio runtime
+    pub fn new(port: SerialPortBuilder) -> tokio_serial::Result<Self> {
+        Ok(Serial {
+            port: SerialStream::open(&port)?,
+        })
+    }
+}
+
+impl embedded_io_async::ErrorType for Serial {
+    type Error = io::Error;
+}
+
+impl embedded_io_async::Read for Serial {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        self.port.read(buf).await
+    }
+}
+
+impl embedded_io_async::Write for Serial {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.port.write(buf).await
+    }
+
+    async fn flush(&mut self) -> Result<(), io::Error> {
+        self.port.flush().await
+    }
+}
+
+/// tokio timers have a millisecond resolution, so shorter delays are rounded up
+pub struct Delay;
+
+impl embedded_hal_async::delay::DelayNs for Delay {
+    async fn delay_ns(&mut self, ns: u32) {
+        if ns > 0 {
+            tokio::time::sleep(Duration::from_nanos(ns.into())).await;
+        }
+    }
+}
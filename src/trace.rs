@@ -0,0 +1,144 @@
+//! Hook to see every packet on the bus, e.g. to log the traffic
+
+use crate::protocol::{Frame, Instruction};
+use core::fmt;
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Sent,
+    Received,
+}
+
+/// Decoded content of a packet
+#[derive(Debug, Copy, Clone)]
+pub enum Packet<'a> {
+    Instruction {
+        id: u8,
+        instruction: Instruction,
+        params: &'a [u8],
+    },
+    Status {
+        id: u8,
+        error: u8,
+        params: &'a [u8],
+    },
+    Broken {
+        fault: Fault,
+    },
+}
+
+/// Why a packet received could not be decoded
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Fault {
+    Checksum,
+    Instruction, // an Instruction Packet instead of a status
+    TooLong,     // more params than the buffer holds
+    Truncated,   // timeout or serial error before its end
+}
+
+impl Fault {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Checksum => "wrong checksum",
+            Self::Instruction => "instruction packet",
+            Self::TooLong => "too long",
+            Self::Truncated => "truncated",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Trace<'a> {
+    pub direction: Direction,
+    pub timestamp: Option<u64>, // µs since the UNIX epoch, with std
+    pub frame: &'a Frame<'a>,
+    pub packet: Packet<'a>,
+}
+
+/// Called by the Controller with every packet sent or received, broken ones included
+pub type Hook = fn(&Trace<'_>);
+
+/// Time of a trace, if we have a clock
+pub(crate) fn now() -> Option<u64> {
+    #[cfg(feature = "std")]
+    {
+        let since = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH);
+        since
+            .ok()
+            .and_then(|since| u64::try_from(since.as_micros()).ok())
+    }
+    #[cfg(not(feature = "std"))]
+    None
+}
+
+impl fmt::Display for Trace<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(timestamp) = self.timestamp {
+            write!(f, "{timestamp} ")?;
+        }
+        match self.packet {
+            Packet::Instruction {
+                id,
+                instruction,
+                params,
+            } => write!(f, "-> {id} {instruction:?} {params:02X?}")?,
+            Packet::Status { id, error, params } => {
+                write!(f, "<- {id} error 0x{error:02X} {params:02X?}")?
+            }
+            Packet::Broken { fault } => write!(f, "<- broken: {}", fault.as_str())?,
+        }
+        f.write_str(" raw")?;
+        for b in self.frame.iter() {
+            write!(f, " {b:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Trace packets with the `log` crate, at the debug level
+#[cfg(feature = "log")]
+pub fn log(trace: &Trace<'_>) {
+    log::debug!(target: "dmx", "{trace}");
+}
+
+/// Trace packets with `defmt`, at the debug level
+#[cfg(feature = "defmt")]
+pub fn defmt(trace: &Trace<'_>) {
+    let frame = trace.frame;
+    match trace.packet {
+        Packet::Instruction {
+            id,
+            instruction,
+            params,
+        } => defmt::debug!(
+            "-> {=u8} instruction 0x{=u8:02x} {=[u8]:02x} raw {=[u8]:02x} {=[u8]:02x} {=[u8]:02x}",
+            id,
+            instruction as u8,
+            params,
+            &frame.head[..],
+            frame.params,
+            &frame.tail[..]
+        ),
+        Packet::Status { id, error, params } => defmt::debug!(
+            "<- {=u8} error 0x{=u8:02x} {=[u8]:02x} raw {=[u8]:02x} {=[u8]:02x} {=[u8]:02x}",
+            id,
+            error,
+            params,
+            &frame.head[..],
+            frame.params,
+            &frame.tail[..]
+        ),
+        Packet::Broken { fault } => defmt::debug!(
+            "<- broken: {=str} raw {=[u8]:02x} {=[u8]:02x} {=[u8]:02x}",
+            fault.as_str(),
+            &frame.head[..],
+            frame.params,
+            &frame.tail[..]
+        ),
+    }
+}